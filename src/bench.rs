@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use crate::{Day, Error};
+use crate::report::Report;
+
+pub const DEFAULT_OUTPUT: &str = "bench_output.txt";
+
+#[derive(Debug, Copy, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = sorted.iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / len as f64;
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let millis = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        write!(f, "min {:.4}ms, median {:.4}ms, mean {:.4}ms, stddev {:.4}ms",
+               millis(self.min), millis(self.median), millis(self.mean), millis(self.stddev))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    pub total: Stats,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        [("parse", &self.parse), ("part 1", &self.part1), ("part 2", &self.part2), ("total", &self.total)]
+            .into_iter()
+            .try_for_each(|(phase, stats)| {
+                writeln!(f, "Day {:02} {phase:<6} ({} runs): {stats}", self.day, self.runs)
+            })
+    }
+}
+
+/// Runs `day` `warmup` times without measuring, then `runs` times collecting the phase timings.
+/// Gives `None` when there are no runs to compute statistics from.
+pub fn bench(day: &Day, input: &str, warmup: usize, runs: usize) -> Result<Option<BenchReport>, Error> {
+    for _ in 0..warmup {
+        day.solve_caught(input)?;
+    }
+    let reports = (0..runs)
+        .map(|_| day.solve_caught(input))
+        .collect::<Result<Vec<_>, _>>()?;
+    let stats = |phase: fn(&Report) -> Duration| {
+        Stats::from_samples(&reports.iter().map(phase).collect::<Vec<_>>())
+    };
+    let (Some(parse), Some(part1), Some(part2), Some(total)) = (
+        stats(|report| report.parse_time),
+        stats(|report| report.parts[0].time),
+        stats(|report| report.parts[1].time),
+        stats(Report::total_time),
+    ) else {
+        return Ok(None);
+    };
+    Ok(Some(BenchReport { day: day.day, runs, parse, part1, part2, total }))
+}
+
+pub fn write_output<P>(path: P, reports: &[BenchReport]) -> std::io::Result<()> where P: AsRef<Path> {
+    let mut file = File::create(path)?;
+    reports.iter()
+        .try_for_each(|report| write!(file, "{report}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats_of_odd_and_even_sample_counts() {
+        let stats = Stats::from_samples(&millis(&[9, 1, 5])).expect("Has samples");
+        assert_eq!([stats.min, stats.median, stats.mean], millis(&[1, 5, 5])[..]);
+        assert!((stats.stddev.as_secs_f64() - 0.003_265_986).abs() < 1e-9);
+        let stats = Stats::from_samples(&millis(&[4, 2, 8, 6])).expect("Has samples");
+        assert_eq!([stats.min, stats.median, stats.mean], millis(&[2, 5, 5])[..]);
+        assert!((stats.stddev.as_secs_f64() - 0.002_236_068).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_of_a_single_sample() {
+        let stats = Stats::from_samples(&millis(&[7])).expect("Has samples");
+        assert_eq!([stats.min, stats.median, stats.mean], millis(&[7, 7, 7])[..]);
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::{stdout, Write};
+use std::process::ExitCode;
+use aoc23::{check, Day};
+use aoc23::days::{DAYS, find};
+use aoc23::input::Source;
+use aoc23::report::Format;
+use aoc23::runner::{BENCH_OPTIONS, Options, read_input, RUN_OPTIONS, run_bench};
+
+const USAGE: &str = "\
+Usage: aoc list
+       aoc run <day|all> [INPUT|-] [--format text|json|csv] [--check] [--answers DIR] [--inputs DIR]
+       aoc bench <day|all> [INPUT|-] [--warmup N] [--runs N] [--bench-output PATH] [--inputs DIR]";
+
+fn all_inputs(options: &Options) -> impl Iterator<Item=(&'static Day, String)> + '_ {
+    DAYS.iter()
+        .filter_map(|day| {
+            let source = Source::day_file(&options.inputs, day.day);
+            source.read()
+                .map_err(|err| eprintln!("Day {:02}: skipped, cannot read {source}: {err}", day.day))
+                .ok()
+                .map(|input| (day, input))
+        })
+}
+
+/// Returns whether the input parsed and the answers passed the check, if checking.
+fn run_day<W>(out: &mut W, options: &Options, day: &Day, input: &str) -> std::io::Result<bool> where W: Write {
+    if options.format == Format::Text {
+        writeln!(out, "Day {:02}: {}", day.day, day.title)?;
+    }
+    let report = match day.solve_caught(input) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Day {:02}: bad input: {err}", day.day);
+            return Ok(false);
+        }
+    };
+    options.format.write_report(out, &report)?;
+    out.flush()?;
+    Ok(!options.check || check::check_report(&options.answers, &report))
+}
+
+fn main() -> ExitCode {
+    let options = Options::from_env();
+    let command = options.positional.first().map_or("aoc", String::as_str);
+    let allowed = match command {
+        "run" => RUN_OPTIONS,
+        "bench" => BENCH_OPTIONS,
+        _ => &[],
+    };
+    if let Err(err) = options.only(allowed, command) {
+        eprintln!("{err}");
+        return ExitCode::from(2);
+    }
+    let mut out = stdout().lock();
+    match options.positional.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["list"] => {
+            for day in DAYS {
+                println!("{:02} {}", day.day, day.title);
+            }
+        }
+        ["run", "all"] => {
+            options.format.write_header(&mut out).expect("Cannot write report");
+            let mut ok = true;
+            for day in DAYS {
+                let source = Source::day_file(&options.inputs, day.day);
+                match source.read() {
+                    Ok(input) => ok &= run_day(&mut out, &options, day, &input).expect("Cannot write report"),
+                    // A day without input cannot be checked, which must not let the check pass
+                    Err(err) if options.check => {
+                        eprintln!("Day {:02}: FAIL, cannot read {source}: {err}", day.day);
+                        ok = false;
+                    }
+                    Err(err) => eprintln!("Day {:02}: skipped, cannot read {source}: {err}", day.day),
+                }
+            }
+            if !ok {
+                return ExitCode::FAILURE;
+            }
+        }
+        ["bench", "all"] => {
+            run_bench(&options, all_inputs(&options));
+        }
+        ["bench", day, rest @ ..] if rest.len() <= 1 => {
+            let Some(day) = day.parse().ok().and_then(find) else {
+                eprintln!("Unknown day: {day}");
+                return ExitCode::FAILURE;
+            };
+            let input = read_input(&options.input_source(day.day, rest.first().copied()));
+            run_bench(&options, [(day, input)]);
+        }
+        ["run", day, rest @ ..] if rest.len() <= 1 => {
+            let Some(day) = day.parse().ok().and_then(find) else {
+                eprintln!("Unknown day: {day}");
+                return ExitCode::FAILURE;
+            };
+            let input = read_input(&options.input_source(day.day, rest.first().copied()));
+            let ok = options.format.write_header(&mut out)
+                .and_then(|_| run_day(&mut out, &options, day, &input))
+                .expect("Cannot write report");
+            if !ok {
+                return ExitCode::FAILURE;
+            }
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
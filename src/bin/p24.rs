@@ -0,0 +1,6 @@
+use aoc23::days::p24::Day24;
+use aoc23::run;
+
+fn main() {
+    run!(Day24);
+}
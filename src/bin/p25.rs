@@ -0,0 +1,6 @@
+use aoc23::days::p25::Day25;
+use aoc23::run;
+
+fn main() {
+    run!(Day25);
+}
@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::report::Report;
+
+pub const DEFAULT_DIR: &str = "answers";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Missing,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartCheck {
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+}
+
+impl PartCheck {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Mismatch { .. })
+    }
+}
+
+impl Display for PartCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.day, self.part)?;
+        match &self.verdict {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Mismatch { expected, actual } => write!(f, "MISMATCH (expected {expected}, got {actual})"),
+            Verdict::Missing => write!(f, "no expected answer"),
+        }
+    }
+}
+
+pub fn answers_path<P>(dir: P, day: u8) -> PathBuf where P: AsRef<Path> {
+    dir.as_ref().join(format!("{day:02}.txt"))
+}
+
+/// Expected answers are stored one line per part; an empty line means the part is not known yet.
+pub fn load_answers<P>(path: P) -> std::io::Result<Vec<Option<String>>> where P: AsRef<Path> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .map(|line| if line.is_empty() { None } else { Some(line.to_string()) })
+        .collect())
+}
+
+pub fn check(report: &Report, expected: &[Option<String>]) -> Vec<PartCheck> {
+    report.parts.iter()
+        .zip(1..)
+        .map(|(part, n)| {
+            let verdict = match expected.get(n as usize - 1) {
+                Some(Some(expected)) if *expected == part.answer => Verdict::Pass,
+                Some(Some(expected)) => Verdict::Mismatch { expected: expected.clone(), actual: part.answer.clone() },
+                _ => Verdict::Missing,
+            };
+            PartCheck { day: report.day, part: n, verdict }
+        })
+        .collect()
+}
+
+/// Checks `report` against the answers stored in `dir` and prints the verdicts to stderr.
+/// Returns whether all known answers matched; an unreadable answer file counts as a failure.
+pub fn check_report<P>(dir: P, report: &Report) -> bool where P: AsRef<Path> {
+    let path = answers_path(dir, report.day);
+    match load_answers(&path) {
+        Ok(expected) => {
+            let checks = check(report, &expected);
+            checks.iter().for_each(|check| eprintln!("{check}"));
+            !checks.iter().any(PartCheck::is_failure)
+        }
+        Err(err) => {
+            eprintln!("Day {:02}: FAIL, cannot read {}: {err}", report.day, path.display());
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::report::PartReport;
+    use super::*;
+
+    fn report(answers: [&str; 2]) -> Report {
+        Report {
+            day: 7,
+            parse_time: Duration::ZERO,
+            parts: answers.map(|answer| PartReport { answer: answer.to_string(), time: Duration::ZERO }),
+        }
+    }
+
+    fn verdicts(answers: [&str; 2], expected: &[Option<&str>]) -> Vec<Verdict> {
+        let expected = expected.iter().map(|answer| answer.map(str::to_string)).collect::<Vec<_>>();
+        check(&report(answers), &expected).into_iter()
+            .map(|check| check.verdict)
+            .collect()
+    }
+
+    #[test]
+    fn checks_each_part() {
+        assert_eq!(verdicts(["1", "2"], &[Some("1"), Some("2")]), [Verdict::Pass, Verdict::Pass]);
+        assert_eq!(verdicts(["1", "3"], &[Some("1"), Some("2")]),
+                   [Verdict::Pass, Verdict::Mismatch { expected: "2".to_string(), actual: "3".to_string() }]);
+        let checks = check(&report(["1", "2"]), &[Some("1".to_string())]);
+        assert_eq!(checks[1], PartCheck { day: 7, part: 2, verdict: Verdict::Missing });
+        assert!(checks.iter().all(|check| !check.is_failure()));
+    }
+
+    #[test]
+    fn unknown_answers_are_missing() {
+        assert_eq!(verdicts(["1", "2"], &[None, Some("2")]), [Verdict::Missing, Verdict::Pass]);
+        assert_eq!(verdicts(["1", "2"], &[]), [Verdict::Missing, Verdict::Missing]);
+    }
+
+    #[test]
+    fn unreadable_answers_fail() {
+        let dir = std::env::temp_dir().join("aoc23-missing-answers");
+        assert!(!answers_path(&dir, 7).exists());
+        assert!(!check_report(&dir, &report(["1", "2"])));
+    }
+}
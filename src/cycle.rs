@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...`: after `prefix` states
+/// the sequence repeats every `period` states.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Earliest step holding the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Detects the cycle by remembering every state seen, stepping `prefix + period` times.
+pub fn find_cycle<S, F>(start: S, step: F) -> Cycle where S: Clone + Hash + Eq, F: FnMut(&S) -> S {
+    let Ok((_, cycle)) = run_until_cycle(start, step, usize::MAX) else {
+        panic!("No cycle within usize::MAX steps")
+    };
+    cycle
+}
+
+/// Detects the cycle with Brent's algorithm, keeping only two states in memory at the cost of
+/// stepping a few more times than [`find_cycle`].
+pub fn find_cycle_brent<S, F>(start: S, mut step: F) -> Cycle where S: Clone + Eq, F: FnMut(&S) -> S {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start, |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// State after `n` steps, skipping over whole periods once the sequence starts repeating.
+pub fn nth_state<S, F>(start: S, step: F, n: usize) -> S where S: Clone + Hash + Eq, F: FnMut(&S) -> S {
+    match run_until_cycle(start, step, n) {
+        Ok((mut history, cycle)) => history.swap_remove(cycle.equivalent_step(n)),
+        Err(state) => state,
+    }
+}
+
+/// Steps until a state repeats, returning the states seen in order, or the state at step `limit`
+/// when none repeated before it.
+fn run_until_cycle<S, F>(start: S, mut step: F, limit: usize) -> Result<(Vec<S>, Cycle), S>
+    where
+        S: Clone + Hash + Eq,
+        F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = start;
+    for n in 0..limit {
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let prefix = *entry.get();
+                return Ok((history, Cycle { prefix, period: n - prefix }));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(n);
+            }
+        }
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    Err(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3 lead into the loop 4, 5, 6, 7, 8, 4, ...
+    fn step(n: &u32) -> u32 {
+        if *n == 8 { 4 } else { n + 1 }
+    }
+
+    #[test]
+    fn both_detectors_agree() {
+        let expected = Cycle { prefix: 4, period: 5 };
+        assert_eq!(find_cycle(0, step), expected);
+        assert_eq!(find_cycle_brent(0, step), expected);
+        assert_eq!(find_cycle(6, step), Cycle { prefix: 0, period: 5 });
+        assert_eq!(find_cycle_brent(6, step), Cycle { prefix: 0, period: 5 });
+        assert_eq!(find_cycle_brent(7, |_| 7), Cycle { prefix: 0, period: 1 });
+    }
+
+    #[test]
+    fn fast_forwards() {
+        let cycle = find_cycle(0, step);
+        assert_eq!(cycle.equivalent_step(3), 3);
+        assert_eq!(cycle.equivalent_step(9), 4);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 5);
+        assert_eq!(nth_state(0, step, 1_000_000_000), 5);
+        for n in 0..20 {
+            assert_eq!(nth_state(0, step, n), (0..n).fold(0, |state, _| step(&state)));
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::Day;
+
+macro_rules! register_days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[Day] = &[$(Day::new::<$module::$solution>()),*];
+    };
+}
+
+register_days! {
+    p01::Day01,
+    p02::Day02,
+    p03::Day03,
+    p04::Day04,
+    p05::Day05,
+    p06::Day06,
+    p07::Day07,
+    p08::Day08,
+    p09::Day09,
+    p10::Day10,
+    p11::Day11,
+    p12::Day12,
+    p13::Day13,
+    p14::Day14,
+    p15::Day15,
+    p16::Day16,
+    p17::Day17,
+    p18::Day18,
+    p19::Day19,
+    p20::Day20,
+    p21::Day21,
+    p22::Day22,
+    p23::Day23,
+    p24::Day24,
+    p25::Day25,
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use crate::{Error, parse_lines, Solution};
+
+pub struct Day01;
+
+static FIRST_DIGIT: Lazy<Regex> = Lazy::new(|| Regex::new(r"one|two|three|four|five|six|seven|eight|nine|[1-9]").expect("Infallible"));
+static LAST_DIGIT: Lazy<Regex> = Lazy::new(|| Regex::new(r"(.*)(one|two|three|four|five|six|seven|eight|nine|[1-9])").expect("Infallible"));
+
+fn parse_digit<S>(v: S) -> u32 where S: AsRef<str> {
+    static MAP: Lazy<HashMap<&str, u32>> = Lazy::new(|| [("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9)].into_iter().collect());
+    let v = v.as_ref();
+    if v.len() == 1 {
+        v.chars().next().expect("Infallible").to_digit(10).expect("Infallible")
+    } else {
+        *MAP.get(v).expect("Infallible")
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            if line.contains(|c: char| c.is_ascii_digit()) || FIRST_DIGIT.is_match(line) {
+                Ok(line.to_string())
+            } else {
+                Err(Error::new("No digit or digit word"))
+            }
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Output1 {
+        // Lines that spell out all their digits only count in part 2
+        lines.iter().filter_map(|line| {
+            let first = line.chars()
+                .find_map(|c| c.to_digit(10))?;
+            let last = line.chars()
+                .rev()
+                .find_map(|c| c.to_digit(10))?;
+            Some(first * 10 + last)
+        }).sum::<u32>()
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Output2 {
+        // parse only keeps lines with a digit or digit word, so both searches find one
+        lines.iter().map(|line| {
+            let first = FIRST_DIGIT
+                .find(line.as_ref())
+                .map(|m| parse_digit(m.as_str()))
+                .expect("Infallible");
+            let last = LAST_DIGIT
+                .captures(line.as_ref())
+                .and_then(|c| c.get(2).map(|m| parse_digit(m.as_str())))
+                .expect("Infallible");
+            first * 10 + last
+        }).sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part1_example() {
+        let input = Day01::parse(EXAMPLE_1).expect("Bad input");
+        assert_eq!(Day01::part1(&input), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day01::parse(EXAMPLE_2).expect("Bad input");
+        assert_eq!(Day01::part2(&input), 281);
+    }
+
+    #[test]
+    fn lines_need_a_digit() {
+        let err = Day01::parse("1abc2\nabc").expect_err("No digit");
+        assert_eq!(err.to_string(), "line 2, column 1: No digit or digit word");
+        assert_eq!(Day01::part1(&Day01::parse("1abc2\nxtwone").expect("Bad input")), 12);
+    }
+}
@@ -0,0 +1,141 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+use crate::{Error, parse_lines, parse_token, Solution};
+
+pub struct Day02;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, rounds) = s.split_once(':').ok_or_else(|| Error::new("Missing ':'"))?;
+        let id = header.strip_prefix("Game ")
+            .ok_or_else(|| Error::new("Expected \"Game <id>\"").within(s, header))
+            .and_then(|id| parse_token(s, id))?;
+        let rounds = rounds.split(';')
+            .map(|round| Round::from_str(round).map_err(|err| err.within(s, round)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Game { id, rounds })
+    }
+}
+
+#[derive(Default, Debug, Eq, PartialEq)]
+struct Round {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl PartialOrd<Self> for Round {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.red >= other.red &&
+            self.green >= other.green &&
+            self.blue >= other.blue {
+            Some(Ordering::Greater)
+        } else {
+            Some(Ordering::Less)
+        }
+    }
+}
+
+impl FromStr for Round {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',').map(str::trim).try_fold(Round::default(), |mut round, color_pair| {
+            let (count, color) = color_pair.split_once(' ')
+                .ok_or_else(|| Error::new("Expected \"<count> <color>\"").within(s, color_pair))?;
+            let count = parse_token(s, count)?;
+            match color {
+                "red" => round.red = count,
+                "green" => round.green = count,
+                "blue" => round.blue = count,
+                _ => return Err(Error::new(format!("Unknown color: {color}")).within(s, color)),
+            };
+            Ok(round)
+        })
+    }
+}
+
+impl Round {
+    fn maximum(&self, other: &Self) -> Self {
+        Self {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    type Input = Vec<Game>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, Game::from_str)
+    }
+
+    fn part1(games: &Self::Input) -> Self::Output1 {
+        let ref_round = Round {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        games.iter().filter_map(|game| {
+            if game.rounds.iter().all(|round| {
+                ref_round >= *round
+            }) {
+                Some(game.id)
+            } else {
+                None
+            }
+        }).sum::<u32>()
+    }
+
+    fn part2(games: &Self::Input) -> Self::Output2 {
+        games.iter().map(|game| {
+            game.rounds.iter().fold(Round::default(), |acc, round| {
+                acc.maximum(round)
+            }).power()
+        }).sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn part1_example() {
+        let input = Day02::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day02::part1(&input), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day02::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day02::part2(&input), 2286);
+    }
+}
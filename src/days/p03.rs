@@ -0,0 +1,113 @@
+use itertools::Itertools;
+use crate::{Coord, Error, Grid, Solution, some, xy};
+
+pub struct Day03;
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<(u32, Vec<Coord>)>,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    type Input = Schematic;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let grid = Grid::from_input(input.lines(), some)?;
+        let numbers = grid.iter().enumerate().flat_map(|(y, line)| {
+            line.iter().enumerate().map(move |(x, &c)| (xy!(x, y), c))
+        }).group_by(|&(coord, c)| {
+            if c.is_ascii_digit() {
+                Some(coord.y)
+            } else {
+                None
+            }
+        }).into_iter().filter_map(|(is_digit, rhs)| {
+            is_digit.map(|_| rhs)
+        }).map(|group| {
+            let group = group.collect::<Vec<_>>();
+            let n = group.iter().map(|&(_, c)| c).collect::<String>().parse::<u32>().expect("Infallible");
+            let indexes = group.iter()
+                .flat_map(|(coord, _)| coord.neighbours8(&grid))
+                .filter(|neighbour| !group.iter().any(|(coord, _)| coord == neighbour))
+                .unique()
+                .collect::<Vec<_>>();
+            (n, indexes)
+        }).collect::<Vec<_>>();
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part1(schematic: &Self::Input) -> Self::Output1 {
+        schematic.numbers.iter().filter_map(|(n, indexes)| {
+            if indexes.iter().any(|&coord| schematic.grid[coord] != '.') {
+                Some(*n)
+            } else {
+                None
+            }
+        }).sum::<u32>()
+    }
+
+    fn part2(schematic: &Self::Input) -> Self::Output2 {
+        schematic.numbers.iter().flat_map(|(n, indexes)| {
+            indexes.iter().filter_map({
+                let grid = &schematic.grid;
+                move |&coord| {
+                    if grid[coord] == '*' {
+                        Some((coord, *n))
+                    } else {
+                        None
+                    }
+                }
+            })
+        }).sorted_by_key(|&(coord, _)| coord).group_by(|&(coord, _)| coord).into_iter().map(|(_, mut group)| {
+            group.try_fold((0, 1), |(count, product), (_, n)| {
+                match count {
+                    0 | 1 => {
+                        Some((count + 1, product * n))
+                    }
+                    _ => None
+                }
+            })
+        }).filter_map(|gear| {
+            gear.and_then(|(count, product)| {
+                if count == 2 {
+                    Some(product)
+                } else {
+                    None
+                }
+            })
+        }).sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part1_example() {
+        let input = Day03::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day03::part1(&input), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day03::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day03::part2(&input), 467835);
+    }
+}
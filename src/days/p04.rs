@@ -0,0 +1,79 @@
+use std::collections::{HashSet, VecDeque};
+use std::iter::repeat_n;
+use crate::{Error, parse_lines, parse_token, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let (_, numbers) = line.split_once(':').ok_or_else(|| Error::new("Missing ':'"))?;
+            let (winning_numbers, betting_numbers) = numbers.split_once('|')
+                .ok_or_else(|| Error::new("Missing '|'").within(line, numbers))?;
+            let parse_numbers = |numbers: &str| {
+                numbers.split_whitespace()
+                    .map(|number| parse_token::<u32>(line, number))
+                    .collect::<Result<HashSet<_>, _>>()
+            };
+            let betting_numbers = parse_numbers(betting_numbers)?;
+            Ok(parse_numbers(winning_numbers)?
+                .iter()
+                .filter(|number| betting_numbers.contains(number))
+                .count())
+        })
+    }
+
+    fn part1(winning_counts: &Self::Input) -> Self::Output1 {
+        winning_counts.iter()
+            .map(|winnings| {
+                match winnings {
+                    0 => 0,
+                    1.. => 1 << (winnings - 1),
+                }
+            }).sum::<usize>()
+    }
+
+    fn part2(winning_counts: &Self::Input) -> Self::Output2 {
+        winning_counts.iter()
+            .fold((0, VecDeque::new()), |(total_cards, mut forward_winnings), &numbers_won| {
+                let cards = forward_winnings.pop_front().unwrap_or(0usize) + 1;
+                forward_winnings.extend(repeat_n(0, numbers_won.saturating_sub(forward_winnings.len())));
+                forward_winnings
+                    .iter_mut()
+                    .take(numbers_won)
+                    .for_each(|forward_duplicate| *forward_duplicate += cards);
+                (total_cards + cards, forward_winnings)
+            }).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn part1_example() {
+        let input = Day04::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day04::part1(&input), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day04::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day04::part2(&input), 30);
+    }
+}
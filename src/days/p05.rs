@@ -0,0 +1,213 @@
+use std::collections::BTreeSet;
+use std::ops::{Range, RangeBounds};
+use itertools::Itertools;
+use rangemap::{RangeMap, RangeSet};
+use crate::{Error, parse_token, sections, Solution};
+
+pub struct Day05;
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
+struct RangeMapping {
+    src: u64,
+    dst: u64,
+    len: u64,
+}
+
+impl RangeMapping {
+    fn new(src: u64, dst: u64, len: u64) -> Self {
+        Self { src, dst, len }
+    }
+
+    fn search_only(src: u64) -> Self {
+        Self { src, dst: 0, len: 0 }
+    }
+
+    fn search_range(start: u64, end: u64) -> impl RangeBounds<RangeMapping> {
+        Self::search_only(start)..=Self::search_only(end)
+    }
+
+    fn map(&self, n: u64) -> Option<u64> {
+        if (self.src..self.src + self.len).contains(&n) {
+            Some(n + self.dst - self.src)
+        } else {
+            None
+        }
+    }
+
+    fn map_range(&self, range: Range<u64>) -> Option<Range<u64>> {
+        Some(self.map(range.start)?..self.map(range.end - 1)? + 1)
+    }
+}
+
+fn union<T>(lhs: &Range<T>, rhs: &Range<T>) -> Range<T> where T: Ord + Copy {
+    lhs.start.max(rhs.start)..lhs.end.min(rhs.end)
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    mappings: Vec<Vec<RangeMapping>>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    type Input = Almanac;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut segments = sections(input);
+        let seed_section = segments
+            .next()
+            .ok_or_else(|| Error::new("Missing seeds"))?;
+        let seeds = seed_section
+            .strip_prefix("seeds:")
+            .ok_or_else(|| Error::new("Expected \"seeds:\"").within(input, seed_section))?
+            .split_whitespace()
+            .map(|s| parse_token(input, s))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Part 2 reads the seeds as pairs of start and length, which must cover at least one seed
+        if seeds.is_empty() || seeds.len() % 2 != 0 || seeds.iter().skip(1).step_by(2).any(|&len| len == 0) {
+            return Err(Error::new("Expected pairs of seed start and non-zero length").within(input, seed_section));
+        }
+        let mappings = segments
+            .map(|segment| {
+                segment
+                    .lines()
+                    .skip(1)
+                    .map(|line| {
+                        let nums = line.split_whitespace()
+                            .map(|s| parse_token(input, s))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        match nums[..] {
+                            [dst, src, len] => Ok(RangeMapping::new(src, dst, len)),
+                            _ => Err(Error::new("Expected 3 numbers").within(input, line)),
+                        }
+                    }).collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, mappings })
+    }
+
+    fn part1(almanac: &Self::Input) -> Self::Output1 {
+        almanac.mappings
+            .iter()
+            .map(|mappings| mappings.iter().cloned().collect::<BTreeSet<_>>())
+            .fold(almanac.seeds.clone(), |idxs, range_set| {
+                idxs.into_iter()
+                    .map(|idx| {
+                        range_set
+                            .range(RangeMapping::search_range(0, idx))
+                            .next_back()
+                            .and_then(|mapping| {
+                                mapping.map(idx)
+                            })
+                            .unwrap_or(idx)
+                    })
+                    .collect()
+            })
+            .into_iter()
+            .min()
+            // parse checks there are seeds
+            .expect("Infallible")
+    }
+
+    fn part2(almanac: &Self::Input) -> Self::Output2 {
+        let seed_ranges = almanac.seeds.iter()
+            .cloned()
+            .tuples()
+            .map(|(start, len)| {
+                start..start + len
+            })
+            .collect::<RangeSet<_>>();
+        almanac.mappings
+            .iter()
+            .map(|mappings| {
+                mappings.iter()
+                    .map(|&mapping| (mapping.src..mapping.src + mapping.len, mapping))
+                    .collect::<RangeMap<_, _>>()
+            })
+            .fold(seed_ranges, |ranges, range_set| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| {
+                        range_set
+                            .overlapping(&range)
+                            .map(|(mapping_range, mapping)| {
+                                mapping.map_range(union(&range, mapping_range)).expect("Infallible")
+                            })
+                            .chain(
+                                range_set
+                                    .gaps(&range)
+                            ).collect::<RangeSet<_>>()
+                    })
+                    .collect()
+            })
+            .into_iter()
+            .next()
+            // parse checks the seed ranges are not empty, and mapping keeps their size
+            .expect("Infallible")
+            .start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn part1_example() {
+        let input = Day05::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day05::part1(&input), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day05::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day05::part2(&input), 46);
+    }
+
+    #[test]
+    fn needs_seed_ranges() {
+        let err = |input: &str| Day05::parse(input).err().map(|err| err.to_string());
+        assert_eq!(err("x").as_deref(), Some("line 1, column 1: Expected \"seeds:\""));
+        assert_eq!(err("seeds:").as_deref(), Some("line 1, column 1: Expected pairs of seed start and non-zero length"));
+        assert_eq!(err("seeds: 1 2 3").as_deref(), Some("line 1, column 1: Expected pairs of seed start and non-zero length"));
+        assert!(Day05::parse("seeds: 1 2").is_ok());
+    }
+}
@@ -0,0 +1,85 @@
+use std::iter::zip;
+use crate::{Error, parse_token, Solution};
+
+pub struct Day06;
+
+pub struct Races {
+    times: Vec<u32>,
+    distances: Vec<u32>,
+    time: u64,
+    distance: u64,
+}
+
+fn parse_numbers(input: &str, line: &str) -> Result<Vec<u32>, Error> {
+    line.split_whitespace()
+        .skip(1)
+        .map(|number| parse_token(input, number))
+        .collect()
+}
+
+fn parse_joined_number(input: &str, line: &str) -> Result<u64, Error> {
+    line.chars()
+        .filter(|c| c.is_numeric())
+        .collect::<String>()
+        .parse::<u64>()
+        .map_err(|err| Error::from(err).within(input, line))
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    type Input = Races;
+    type Output1 = u32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let lines: [&str; 2] = input.lines().collect::<Vec<_>>().try_into()
+            .map_err(|_| Error::new("Expected 2 lines"))?;
+        Ok(Races {
+            times: parse_numbers(input, lines[0])?,
+            distances: parse_numbers(input, lines[1])?,
+            time: parse_joined_number(input, lines[0])?,
+            distance: parse_joined_number(input, lines[1])?,
+        })
+    }
+
+    fn part1(races: &Self::Input) -> Self::Output1 {
+        zip(races.times.iter(), races.distances.iter())
+            .map(|(&t, &l)| {
+                let d = ((t * t - 4 * l) as f64).sqrt();
+                let p1 = ((t as f64 - d) / 2.0).floor() as u32;
+                let p2 = ((t as f64 + d) / 2.0).floor() as u32;
+                p2 - p1 - if d.fract() == 0.0 { 1 } else { 0 }
+            })
+            .product::<u32>()
+    }
+
+    fn part2(races: &Self::Input) -> Self::Output2 {
+        let (t, l) = (races.time, races.distance);
+        let d = ((t * t - 4 * l) as f64).sqrt();
+        let p1 = ((t as f64 - d) / 2.0).floor() as u64;
+        let p2 = ((t as f64 + d) / 2.0).floor() as u64;
+        p2 - p1 - if d.fract() == 0.0 { 1 } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn part1_example() {
+        let input = Day06::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day06::part1(&input), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day06::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day06::part2(&input), 71503);
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{Error, parse_lines, parse_token, Solution};
+use self::HandType::{FiveOfKind, FourOfKind, FullHouse, HighCard, OnePair, ThreeOfKind, TwoPair};
+
+pub struct Day07;
+
+pub trait Card {}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum Card1 {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum Card2 {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card for Card1 {}
+impl Card for Card2 {}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind,
+}
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub struct Hand<T> where T: Card {
+    hand_type: HandType,
+    cards: [T; 5],
+    bet: u64,
+}
+
+impl TryFrom<char> for Card1 {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Card1::Two),
+            '3' => Ok(Card1::Three),
+            '4' => Ok(Card1::Four),
+            '5' => Ok(Card1::Five),
+            '6' => Ok(Card1::Six),
+            '7' => Ok(Card1::Seven),
+            '8' => Ok(Card1::Eight),
+            '9' => Ok(Card1::Nine),
+            'T' => Ok(Card1::Ten),
+            'J' => Ok(Card1::Jack),
+            'Q' => Ok(Card1::Queen),
+            'K' => Ok(Card1::King),
+            'A' => Ok(Card1::Ace),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<char> for Card2 {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'J' => Ok(Card2::Joker),
+            '2' => Ok(Card2::Two),
+            '3' => Ok(Card2::Three),
+            '4' => Ok(Card2::Four),
+            '5' => Ok(Card2::Five),
+            '6' => Ok(Card2::Six),
+            '7' => Ok(Card2::Seven),
+            '8' => Ok(Card2::Eight),
+            '9' => Ok(Card2::Nine),
+            'T' => Ok(Card2::Ten),
+            'Q' => Ok(Card2::Queen),
+            'K' => Ok(Card2::King),
+            'A' => Ok(Card2::Ace),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<&[Card1; 5]> for HandType {
+    fn from(value: &[Card1; 5]) -> Self {
+        let counts = value.iter().copied().counts();
+        match counts.len() {
+            1 => FiveOfKind,
+            2 => {
+                if counts.values().any(|&x| x == 4) {
+                    FourOfKind
+                } else {
+                    FullHouse
+                }
+            }
+            3 => {
+                if counts.values().any(|&x| x == 3) {
+                    ThreeOfKind
+                } else {
+                    TwoPair
+                }
+            }
+            4 => OnePair,
+            5 => HighCard,
+            _ => panic!("Infallible"),
+        }
+    }
+}
+
+impl From<&[Card2; 5]> for HandType {
+    fn from(value: &[Card2; 5]) -> Self {
+        let counts = value.iter().copied().counts();
+        let jokers = value.iter().filter(|&&card| card == Card2::Joker).count();
+        match (counts.len(), jokers) {
+            (1, _) => FiveOfKind,
+            (2, 0) => {
+                if counts.values().any(|&x| x == 4) {
+                    FourOfKind
+                } else {
+                    FullHouse
+                }
+            }
+            (2, _) => FiveOfKind,
+            (3, 0) => {
+                if counts.values().any(|&x| x == 3) {
+                    ThreeOfKind
+                } else {
+                    TwoPair
+                }
+            }
+            (3, 2 | 3) => FourOfKind,
+            (3, 1) => {
+                if counts.values().any(|&x| x == 3) {
+                    FourOfKind
+                } else {
+                    FullHouse
+                }
+            }
+            (4, 0) => OnePair,
+            (4, 1 | 2) => ThreeOfKind,
+            (5, 0) => HighCard,
+            (5, 1) => OnePair,
+            _ => panic!("Infallible"),
+        }
+    }
+}
+
+impl<T> FromStr for Hand<T>
+    where
+        T: Card + TryFrom<char>,
+        for<'a> HandType: From<&'a [T; 5]>, {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            Some((cards, bet)) => {
+                let cards: [T; 5] = cards
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        T::try_from(c).map_err(|_| Error {
+                            line: None,
+                            column: Some(i + 1),
+                            reason: format!("Invalid card character {c:?}"),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .try_into()
+                    .map_err(|_| Error::new("Bad number of cards"))?;
+                let bet = parse_token(s, bet)?;
+                let hand_type = (&cards).into();
+                Ok(Hand {
+                    hand_type,
+                    cards,
+                    bet,
+                })
+            }
+            None => Err(Error::new("No space"))
+        }
+    }
+}
+
+fn total_winnings<T>(hands: &[Hand<T>]) -> u64 where T: Card + Ord + Clone {
+    hands.iter()
+        .sorted()
+        .zip(1..)
+        .map(|(hand, rank)| {
+            rank * hand.bet
+        }).sum::<u64>()
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    type Input = (Vec<Hand<Card1>>, Vec<Hand<Card2>>);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let hands1 = parse_lines(input, str::parse::<Hand<Card1>>)?;
+        let hands2 = parse_lines(input, str::parse::<Hand<Card2>>)?;
+        Ok((hands1, hands2))
+    }
+
+    fn part1((hands, _): &Self::Input) -> Self::Output1 {
+        total_winnings(hands)
+    }
+
+    fn part2((_, hands): &Self::Input) -> Self::Output2 {
+        total_winnings(hands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn part1_example() {
+        let input = Day07::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day07::part1(&input), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day07::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day07::part2(&input), 5905);
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+use num::integer::lcm;
+use crate::{Error, Solution};
+use self::Direction::{Left, Right};
+
+pub struct Day08;
+
+struct Node {
+    left: usize,
+    right: usize,
+}
+
+enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    instruction_count: usize,
+    nodes: HashMap<String, usize>,
+    graph: Vec<usize>,
+}
+
+impl Network {
+    fn path_len<F>(&self, start: usize, is_end: F) -> usize where F: Fn(usize) -> bool {
+        (0usize..)
+            .fold_while(start, |n, len| {
+                if is_end(n) {
+                    Done(len)
+                } else {
+                    Continue(self.graph[n])
+                }
+            }).into_inner() * self.instruction_count
+    }
+
+    fn indexes_ending_with(&self, suffix: char) -> Vec<usize> {
+        self.nodes.iter()
+            .filter_map(|(name, &n)| {
+                if name.ends_with(suffix) {
+                    Some(n)
+                } else {
+                    None
+                }
+            }).collect()
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    type Input = Network;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut lines = input.lines();
+        let instructions = lines
+            .next()
+            .ok_or_else(|| Error::new("Empty input"))?
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                'L' => Ok(Left),
+                'R' => Ok(Right),
+                _ => Err(Error { line: Some(1), column: Some(i + 1), reason: format!("Bad instruction {c:?}") }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(Error { line: Some(1), column: None, reason: "No instructions".to_string() });
+        }
+        let nodes = lines
+            .skip(1)
+            .enumerate()
+            .map(|(n, line)| {
+                let (name, directions) = line.split_once('=')
+                    .ok_or_else(|| Error::new("Missing '='").within(input, line))?;
+                let (left, right) = directions.trim()
+                    .strip_prefix('(')
+                    .and_then(|directions| directions.strip_suffix(')'))
+                    .and_then(|directions| directions.split_once(','))
+                    .ok_or_else(|| Error::new("Expected \"(<left>, <right>)\"").within(input, directions))?;
+                Ok((name.trim(), (n, left.trim(), right.trim())))
+            })
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+        if !nodes.keys().any(|name| name.ends_with('A')) {
+            return Err(Error::new("No starting node ending with 'A'"));
+        }
+        if !nodes.keys().any(|name| name.ends_with('Z')) {
+            return Err(Error::new("No ending node ending with 'Z'"));
+        }
+        let node_index = |name: &str| {
+            nodes.get(name)
+                .map(|&(n, _, _)| n)
+                .ok_or_else(|| Error::new(format!("Unknown node: {name}")).within(input, name))
+        };
+        let one_step_graph = nodes
+            .values()
+            .map(|&(n, left, right)| {
+                Ok((n, Node {
+                    left: node_index(left)?,
+                    right: node_index(right)?,
+                }))
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .sorted_by_key(|&(n, _)| n)
+            .map(|(_, node)| node)
+            .collect::<Vec<_>>();
+        let graph = (0..one_step_graph.len())
+            .map(|n| {
+                instructions
+                    .iter()
+                    .fold(n, |n, direction| {
+                        match direction {
+                            Left => one_step_graph[n].left,
+                            Right => one_step_graph[n].right,
+                        }
+                    })
+            })
+            .collect::<Vec<_>>();
+        Ok(Network {
+            instruction_count: instructions.len(),
+            nodes: nodes.into_iter()
+                .map(|(name, (n, _, _))| (name.to_string(), n))
+                .collect(),
+            graph,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Self::Output1 {
+        let starting_index = *network.nodes.get("AAA").expect("Missing node AAA");
+        let ending_index = *network.nodes.get("ZZZ").expect("Missing node ZZZ");
+        network.path_len(starting_index, |n| n == ending_index)
+    }
+
+    fn part2(network: &Self::Input) -> Self::Output2 {
+        let starting_indexes = network.indexes_ending_with('A');
+        let ending_indexes = network.indexes_ending_with('Z');
+        starting_indexes.into_iter().map(|n| {
+            network.path_len(n, |n| ending_indexes.contains(&n))
+        // parse checks there is a node ending with 'A'
+        }).reduce(lcm).expect("Infallible")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1_example_1() {
+        let input = Day08::parse(EXAMPLE_1).expect("Bad input");
+        assert_eq!(Day08::part1(&input), 2);
+    }
+
+    #[test]
+    fn part1_example_2() {
+        let input = Day08::parse(EXAMPLE_2).expect("Bad input");
+        assert_eq!(Day08::part1(&input), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day08::parse(EXAMPLE_3).expect("Bad input");
+        assert_eq!(Day08::part2(&input), 6);
+    }
+
+    #[test]
+    fn needs_instructions_and_start() {
+        let err = |input: &str| Day08::parse(input).err().map(|err| err.to_string());
+        assert_eq!(err("\n\n").as_deref(), Some("line 1: No instructions"));
+        assert_eq!(err("LR\n\nBBB = (BBB, BBB)").as_deref(), Some("No starting node ending with 'A'"));
+        assert_eq!(err("LR\n\nAAA = (AAA, AAA)").as_deref(), Some("No ending node ending with 'Z'"));
+    }
+}
@@ -0,0 +1,76 @@
+use itertools::Itertools;
+use crate::{Error, parse_lines, parse_token, Solution};
+
+pub struct Day09;
+
+fn extrapolate(history: &[i64]) -> (i64, i64) {
+    let end_values = (0..)
+        .scan(history.to_vec(), |history, _| {
+            let ret = if history.iter().any(|&x| x != 0) {
+                Some((*history.first().unwrap(), *history.last().unwrap()))
+            } else {
+                None
+            };
+            *history = history.windows(2)
+                .map(|window| {
+                    window[1] - window[0]
+                })
+                .collect::<Vec<_>>();
+            ret
+        })
+        .collect_vec();
+    end_values.into_iter().rev()
+        .fold((0, 0), |prediction, history| {
+            (history.0 - prediction.0, history.1 + prediction.1)
+        })
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    type Input = Vec<Vec<i64>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            line.split(' ')
+                .map(|number| parse_token(line, number))
+                .collect()
+        })
+    }
+
+    fn part1(histories: &Self::Input) -> Self::Output1 {
+        histories.iter()
+            .map(|history| extrapolate(history).1)
+            .sum()
+    }
+
+    fn part2(histories: &Self::Input) -> Self::Output2 {
+        histories.iter()
+            .map(|history| extrapolate(history).0)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn part1_example() {
+        let input = Day09::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day09::part1(&input), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day09::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day09::part2(&input), 2);
+    }
+}
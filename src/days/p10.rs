@@ -0,0 +1,272 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::zip;
+use std::ops::BitXor;
+use itertools::Itertools;
+use crate::{Grid, xy, some, Coord, Error, Solution};
+
+pub struct Day10;
+
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Node {
+    coord: Coord,
+    neighbours: [Coord; 2],
+}
+
+impl Node {
+    fn new(coord: Coord, neighbours: (Coord, Coord)) -> Self {
+        Self {
+            coord,
+            neighbours: [neighbours.0, neighbours.1],
+        }
+    }
+}
+
+type PathStep = ((Coord, Coord), (Coord, Coord));
+
+#[allow(unused)]
+fn print_path(grid: &Grid<char>, path: &[PathStep]) {
+    let path = path.iter().cloned().flat_map(|((a, b), (c, d))| [a, b, c, d].into_iter()).collect::<HashSet<_>>();
+    grid.iter()
+        .enumerate()
+        .map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .map(|(x, &c)| {
+                    if c == 'S' {
+                        'S'
+                    } else if path.contains(&(x, y).into()) {
+                        'X'
+                    } else {
+                        ' '
+                    }
+                })
+                .for_each(|c| print!("{c}"));
+        }).for_each(|_| println!());
+}
+
+
+pub struct Maze {
+    start_symbol: char,
+    grid: Grid<char>,
+    path: Vec<PathStep>,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    type Input = Maze;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let grid = Grid::from_input(input.lines(), some)?;
+        let mut start = None;
+        let graph = grid.iter_coords()
+            .filter_map(|(Coord { x, y }, c)| {
+                let mut start = None;
+                let neighbours = match c {
+                    '|' => if y > 0 { Some(((x, y - 1), (x, y + 1))) } else { None },
+                    '-' => if x > 0 { Some(((x - 1, y), (x + 1, y))) } else { None },
+                    'F' => Some(((x + 1, y), (x, y + 1))),
+                    '7' => if x > 0 { Some(((x - 1, y), (x, y + 1))) } else { None },
+                    'L' => if y > 0 { Some(((x + 1, y), (x, y - 1))) } else { None },
+                    'J' => if x > 0 && y > 0 { Some(((x - 1, y), (x, y - 1))) } else { None },
+                    'S' => Some({
+                        let mut neightbours = Vec::new();
+                        let mut up = false;
+                        let mut down = false;
+                        let mut left = false;
+                        let mut right = false;
+                        if x > 0 && ['F', 'L', '-'].contains(&grid[xy!(x - 1, y)]) {
+                            neightbours.push((x - 1, y));
+                            left = true;
+                        }
+                        if grid.get(x + 1, y).is_some_and(|c| ['7', 'J', '-'].contains(c)) {
+                            neightbours.push((x + 1, y));
+                            right = true;
+                        }
+                        if y > 0 && ['F', '7', '|'].contains(&grid[xy!(x, y - 1)]) {
+                            neightbours.push((x, y - 1));
+                            up = true
+                        }
+                        if grid.get(x, y + 1).is_some_and(|c| ['L', 'J', '|'].contains(c)) {
+                            neightbours.push((x, y + 1));
+                            down = true;
+                        }
+                        assert_eq!(neightbours.len(), 2);
+                        assert!(start.is_none());
+                        let start_symbol = match (up, down, left, right) {
+                            (true, true, false, false) => '|',
+                            (false, false, true, true) => '-',
+                            (true, false, true, false) => 'J',
+                            (true, false, false, true) => 'L',
+                            (false, true, true, false) => '7',
+                            (false, true, false, true) => 'F',
+                            _ => panic!("Infallible")
+                        };
+                        start = Some((Coord::new(x, y), start_symbol));
+                        (neightbours[0], neightbours[1])
+                    }),
+                    _ => None
+                };
+                neighbours.map(|(n1, n2)| (Coord::new(x, y), Node::new((x, y).into(), (n1.into(), n2.into())), start))
+            })
+            .map(|(coord, neighbours, s)| {
+                if s.is_some() {
+                    start = s;
+                }
+                (coord, neighbours)
+            })
+            .collect::<HashMap<_, _>>();
+        let (start_coord, start_symbol) = start.ok_or_else(|| Error::new("No start tile"))?;
+        let start_neighbours = graph[&start_coord].neighbours;
+        let create_iter = |coord: Coord, start: Coord| {
+            let graph = &graph;
+            (0..).scan((start, coord), move |(prev, curr), _| {
+                if *curr == start {
+                    None
+                } else {
+                    let next: [Coord; 1] = graph[curr].neighbours
+                        .iter()
+                        .cloned()
+                        .filter(|next| *next != *prev).collect::<Vec<_>>().try_into().expect("Bad number of neighbours");
+                    let next = next[0];
+                    *prev = *curr;
+                    *curr = next;
+                    Some((*prev, *curr))
+                }
+            })
+        };
+        let path = zip(create_iter(start_neighbours[0], start_coord), create_iter(start_neighbours[1], start_coord))
+            .take_while(|((lhs, _), (rhs, _))| {
+                *lhs != *rhs
+            })
+            .collect_vec();
+        Ok(Maze { start_symbol, grid, path })
+    }
+
+    fn part1(maze: &Self::Input) -> Self::Output1 {
+        maze.path.len() + 1
+    }
+
+    fn part2(maze: &Self::Input) -> Self::Output2 {
+        let path_nodes = maze.path.iter().cloned().flat_map(|((a, b), (c, d))| [a, b, c, d].into_iter()).collect::<HashSet<_>>();
+        maze.grid.iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.iter()
+                    .enumerate()
+                    .map({
+                        let path_nodes = &path_nodes;
+                        move |(x, &c)| {
+                            if c == 'S' {
+                                maze.start_symbol
+                            } else if path_nodes.contains(&(x, y).into()) {
+                                c
+                            } else {
+                                '.'
+                            }
+                        }
+                    })
+                    .fold((false, Option::<bool>::None, 0usize), |(inside, horizontal_top_down, count), c| {
+                        if let Some(top_down) = horizontal_top_down {
+                            match c {
+                                '-' => (inside, Some(top_down), count),
+                                'J' => (inside.bitxor(!top_down), None, count),
+                                '7' => (inside.bitxor(top_down), None, count),
+                                _ => panic!("Infallible"),
+                            }
+                        } else {
+                            match c {
+                                '|' => (!inside, None, count),
+                                'F' => (inside, Some(false), count),
+                                'L' => (inside, Some(true), count),
+                                '.' => (inside, None, count + inside as usize),
+                                _ => panic!("Infallible"),
+                            }
+                        }
+                    }).2
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+    const EXAMPLE_2: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+    const EXAMPLE_3: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+    const EXAMPLE_4: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+    const EXAMPLE_5: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn part1_example_1() {
+        let input = Day10::parse(EXAMPLE_1).expect("Bad input");
+        assert_eq!(Day10::part1(&input), 4);
+    }
+
+    #[test]
+    fn part1_example_2() {
+        let input = Day10::parse(EXAMPLE_2).expect("Bad input");
+        assert_eq!(Day10::part1(&input), 8);
+    }
+
+    #[test]
+    fn part2_example_1() {
+        let input = Day10::parse(EXAMPLE_3).expect("Bad input");
+        assert_eq!(Day10::part2(&input), 4);
+    }
+
+    #[test]
+    fn part2_example_2() {
+        let input = Day10::parse(EXAMPLE_4).expect("Bad input");
+        assert_eq!(Day10::part2(&input), 8);
+    }
+
+    #[test]
+    fn part2_example_3() {
+        let input = Day10::parse(EXAMPLE_5).expect("Bad input");
+        assert_eq!(Day10::part2(&input), 10);
+    }
+}
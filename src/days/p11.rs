@@ -0,0 +1,106 @@
+use std::collections::BTreeSet;
+use itertools::Itertools;
+use crate::{Coord, Error, Grid, some, Solution};
+
+pub struct Day11;
+
+pub struct Image {
+    galaxies: Vec<Coord>,
+    row_indices: BTreeSet<usize>,
+    col_indices: BTreeSet<usize>,
+}
+
+impl Image {
+    fn distance_sum(&self, expansion_ratio: usize) -> usize {
+        self.galaxies.iter()
+            .enumerate()
+            .map(|(i, &Coord { x: x1, y: y1 })| {
+                self.galaxies[i + 1..self.galaxies.len()].iter()
+                    .map(|&Coord { x: x2, y: y2 }| {
+                        let distance = x1.abs_diff(x2) + y1.abs_diff(y2);
+                        let col_expansion = if x1.abs_diff(x2) > 1 {
+                            self.col_indices.range(x1.min(x2) + 1..x1.max(x2))
+                                .count() * expansion_ratio
+                        } else { 0 };
+                        let row_expansion = if y1.abs_diff(y2) > 1 {
+                            self.row_indices.range(y1.min(y2) + 1..y1.max(y2))
+                                .count() * expansion_ratio
+                        } else { 0 };
+                        distance + col_expansion + row_expansion
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    type Input = Image;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let grid = Grid::from_input(input.lines(), some)?;
+        let row_indices = grid.iter()
+            .enumerate()
+            .filter_map(|(y, line)| {
+                if line.iter().all(|&c| c == '.') {
+                    Some(y)
+                } else {
+                    None
+                }
+            })
+            .collect::<BTreeSet<_>>();
+        let col_indices = (0..grid.width())
+            .filter(|&x| grid.column(x).all(|&c| c == '.'))
+            .collect::<BTreeSet<_>>();
+        let galaxies = grid.positions(|&c| c == '#').collect_vec();
+        Ok(Image { galaxies, row_indices, col_indices })
+    }
+
+    fn part1(image: &Self::Input) -> Self::Output1 {
+        image.distance_sum(1)
+    }
+
+    fn part2(image: &Self::Input) -> Self::Output2 {
+        image.distance_sum(1000000 - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day11::part1(&input), 374);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day11::part2(&input), 82000210);
+    }
+
+    #[test]
+    fn larger_expansion_example() {
+        let image = Day11::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(image.distance_sum(10 - 1), 1030);
+        assert_eq!(image.distance_sum(100 - 1), 8410);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use rayon::prelude::*;
+use crate::{Error, parse_lines, parse_token, Solution};
+
+pub struct Day12;
+
+type CachePerSymbol = HashMap<(Option<usize>, Box<[CellState]>, Box<[usize]>), usize>;
+
+#[derive(Copy, Clone, Hash, Debug, Eq, PartialEq)]
+pub enum CellState {
+    Working,
+    Broken,
+    Unknown,
+}
+
+impl TryFrom<char> for CellState {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<CellState, Self::Error> {
+        match value {
+            '.' => Ok(CellState::Working),
+            '#' => Ok(CellState::Broken),
+            '?' => Ok(CellState::Unknown),
+            _ => Err(value),
+        }
+    }
+}
+
+fn cache_adaptor(previous_run: Option<usize>, cells: &[CellState], runs: &[usize],
+                 cache: &mut CachePerSymbol) -> usize {
+    if let Some(result) = cache.get(&(previous_run, Box::from(cells), Box::from(runs))) {
+        *result
+    } else {
+        let result = count_arrangements(previous_run, cells, runs, cache);
+        cache.insert((previous_run, Box::from(cells), Box::from(runs)), result);
+        result
+    }
+}
+
+fn count_arrangements_current(current: CellState,
+                              previous_run: Option<usize>, cells: &[CellState], runs: &[usize],
+                              cache: &mut CachePerSymbol) -> usize {
+    let next_run = runs[0];
+    match (current, previous_run) {
+        (CellState::Working, Some(previous_run)) => {
+            if previous_run == next_run {
+                cache_adaptor(None, cells, &runs[1..], cache)
+            } else {
+                0
+            }
+        }
+        (CellState::Working, None) => {
+            cache_adaptor(None, cells, runs, cache)
+        }
+        (CellState::Broken, Some(previous_run)) => {
+            if next_run <= previous_run {
+                0
+            } else {
+                cache_adaptor(Some(previous_run + 1), cells, runs, cache)
+            }
+        }
+        (CellState::Broken, None) => {
+            cache_adaptor(Some(1), cells, runs, cache)
+        }
+        (CellState::Unknown, _) => {
+            count_arrangements_current(CellState::Working, previous_run, cells, runs, cache)
+                + count_arrangements_current(CellState::Broken, previous_run, cells, runs, cache)
+        }
+    }
+}
+
+fn count_arrangements(previous_run: Option<usize>, cells: &[CellState], runs: &[usize],
+                      cache: &mut CachePerSymbol) -> usize {
+    match (cells.is_empty(), runs.is_empty()) {
+        (true, true) => {
+            if previous_run.is_none() {
+                1
+            } else {
+                0
+            }
+        }
+        (true, false) => {
+            previous_run.map(|previous_run| {
+                if runs.len() == 1 && runs[0] == previous_run {
+                    1
+                } else {
+                    0
+                }
+            }).unwrap_or(0)
+        }
+        (false, true) => {
+            if cells.contains(&CellState::Broken) {
+                0
+            } else {
+                1
+            }
+        }
+        (false, false) => {
+            if runs.iter().sum::<usize>() + runs.len() - 1 - previous_run.unwrap_or_default() > cells.len() {
+                0
+            } else {
+                count_arrangements_current(cells[0], previous_run, &cells[1..], runs, cache)
+            }
+        }
+    }
+}
+
+fn count_arrangements_entrypoint(cells: &[CellState], runs: &[usize],
+                                 cache: &mut CachePerSymbol) -> usize {
+    cache_adaptor(None, cells, runs, cache)
+}
+
+fn repeat_vec_delimiter<T>(vec: &[T], delimiter: &[T], count: usize) -> Vec<T> where T: Clone {
+    let mut result = Vec::with_capacity((vec.len() + 1) * count);
+    for _ in 0..count {
+        result.extend_from_slice(vec);
+        result.extend_from_slice(delimiter);
+    }
+    for _ in 0..delimiter.len() {
+        result.pop();
+    }
+    result
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+    type Input = Vec<(Vec<CellState>, Vec<usize>)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let (lhs, rhs) = line.split_once(' ').ok_or_else(|| Error::new("Missing ' '"))?;
+            let cells = lhs.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    CellState::try_from(c).map_err(|c| Error {
+                        line: None,
+                        column: Some(i + 1),
+                        reason: format!("Bad spring {c:?}"),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let runs = rhs.split(',')
+                .map(|run| parse_token(line, run))
+                .collect::<Result<Vec<usize>, _>>()?;
+            Ok((cells, runs))
+        })
+    }
+
+    fn part1(springs: &Self::Input) -> Self::Output1 {
+        let mut cache = CachePerSymbol::new();
+        springs.iter()
+            .map(|(cells, runs)| count_arrangements_entrypoint(cells, runs, &mut cache))
+            .sum::<usize>()
+    }
+
+    fn part2(springs: &Self::Input) -> Self::Output2 {
+        // One cache per worker and call, so that no state carries over to the next solve
+        springs.par_iter()
+            .map_init(CachePerSymbol::new, |cache, (cells, runs)| {
+                count_arrangements_entrypoint(&repeat_vec_delimiter(cells, &[CellState::Unknown], 5),
+                                              &repeat_vec_delimiter(runs, &[], 5),
+                                              cache)
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day12::part1(&input), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day12::part2(&input), 525152);
+    }
+
+    #[test]
+    fn repeated_solves_agree() {
+        let first = crate::solve::<Day12>(EXAMPLE).expect("Bad input");
+        let second = crate::solve::<Day12>(EXAMPLE).expect("Bad input");
+        assert_eq!(first.parts.map(|part| part.answer), second.parts.map(|part| part.answer));
+    }
+}
@@ -0,0 +1,80 @@
+use std::iter::zip;
+use crate::{Error, Grid, some, Solution};
+
+pub struct Day13;
+
+/// Rows above the horizontal mirror line when exactly `smudges` cells differ from their reflection.
+fn mirror_row(grid: &Grid<char>, smudges: usize) -> Option<usize> {
+    (1..grid.height()).find(|&y| {
+        zip((0..y).rev(), y..grid.height())
+            .map(|(y1, y2)| {
+                zip(grid.row(y1), grid.row(y2)).filter(|(&lhs, &rhs)| lhs != rhs).count()
+            })
+            .sum::<usize>() == smudges
+    })
+}
+
+fn summarize(grid: &Grid<char>, smudges: usize) -> usize {
+    mirror_row(grid, smudges)
+        .map(|row_axis| row_axis * 100)
+        .or_else(|| mirror_row(&grid.transpose(), smudges))
+        .expect("Not mirrored at all")
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+    type Input = Vec<Grid<char>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::many_from_input(input, some)
+    }
+
+    fn part1(grids: &Self::Input) -> Self::Output1 {
+        grids.iter()
+            .map(|grid| summarize(grid, 0))
+            .sum::<usize>()
+    }
+
+    fn part2(grids: &Self::Input) -> Self::Output2 {
+        grids.iter()
+            .map(|grid| summarize(grid, 1))
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day13::part1(&input), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day13::part2(&input), 400);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Error, Grid, some, xy, Solution};
+use crate::cycle::nth_state;
+
+pub struct Day14;
+
+fn calculate_load(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter()
+                .map(move |&c| {
+                    if c == 'O' {
+                        grid.height() - y
+                    } else {
+                        0
+                    }
+                })
+        })
+        .sum::<usize>()
+}
+
+fn tilt_north(grid: &mut Grid<char>) {
+    for x in 0..grid.width() {
+        let mut free = 0;
+        for y in 0..grid.height() {
+            match grid[xy!(x, y)] {
+                '#' => free = y + 1,
+                'O' => {
+                    grid[xy!(x, y)] = '.';
+                    grid[xy!(x, free)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east by rotating the grid clockwise after every tilt.
+fn spin_cycle(grid: &Grid<char>) -> Grid<char> {
+    (0..4).fold(grid.clone(), |mut grid, _| {
+        tilt_north(&mut grid);
+        grid.rotate_cw()
+    })
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::from_input(input.lines(), some)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Output1 {
+        let mut tilted_grid = grid.clone();
+        tilt_north(&mut tilted_grid);
+        calculate_load(&tilted_grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Output2 {
+        let rotating_grid = nth_state(grid.clone(), spin_cycle, 1_000_000_000);
+        calculate_load(&rotating_grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn part1_example() {
+        let input = Day14::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day14::part1(&input), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day14::part2(&input), 64);
+    }
+}
@@ -0,0 +1,110 @@
+use std::num::Wrapping;
+use smol_str::SmolStr;
+use crate::{Error, Solution};
+
+pub struct Day15;
+
+fn hash<S>(chars: &S) -> u8
+    where
+        S: AsRef<str>
+{
+    chars.as_ref().chars()
+        .fold(Wrapping(0u8), |mut state, c| {
+            state += Wrapping(c.try_into().expect("Infallible"));
+            state *= Wrapping(17);
+            state
+        }).0
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input.lines()
+            .next()
+            .ok_or_else(|| Error::new("Empty input"))?
+            .split(',')
+            .map(str::trim)
+            .filter(|step| !step.is_empty())
+            .map(|step| {
+                let valid = step.is_ascii() && match step.split_once('=') {
+                    Some((_, focal_length)) => focal_length.parse::<u8>().is_ok(),
+                    None => step.ends_with('-'),
+                };
+                if valid {
+                    Ok(step.to_string())
+                } else {
+                    Err(Error::new(format!("Bad step: {step}")).within(input, step))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Self::Output1 {
+        commands.iter()
+            .map(|chars| {
+                hash(chars)
+            })
+            .map(|n| n as u64)
+            .sum::<u64>()
+    }
+
+    fn part2(commands: &Self::Input) -> Self::Output2 {
+        commands.iter()
+            .fold(vec![Vec::<(SmolStr, u8)>::new(); 256], |mut boxes, command| {
+                if let Some((label, focal_length)) = command.split_once('=') {
+                    let focal_length = focal_length.parse::<u8>().expect("Infallible");
+                    let lenses = &mut boxes[hash(&label) as usize];
+                    if let Some(position) = lenses.iter()
+                        .position(|(lens, _)| {
+                            lens == label
+                        }) {
+                        lenses[position].1 = focal_length;
+                    } else {
+                        lenses.push((SmolStr::new(label), focal_length));
+                    }
+                } else if let Some((label, "")) = command.split_once('-') {
+                    let lenses = &mut boxes[hash(&label) as usize];
+                    if let Some(position) = lenses.iter()
+                        .position(|(lens, _)| {
+                            lens == label
+                        }) {
+                        lenses.remove(position);
+                    }
+                }
+                boxes
+            })
+            .into_iter()
+            .enumerate()
+            .flat_map(|(box_id, lens_box)| {
+                lens_box.into_iter()
+                    .enumerate()
+                    .map(move |(lens_position, (_, focal_length))| focal_length as usize * (box_id + 1) * (lens_position + 1))
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn part1_example() {
+        let input = Day15::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day15::part1(&input), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day15::part2(&input), 145);
+    }
+}
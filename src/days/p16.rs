@@ -0,0 +1,134 @@
+use itertools::Itertools;
+use rayon::prelude::*;
+use crate::{Coord, Direction, Error, Grid, Mirror, Solution};
+use crate::search::bfs;
+
+pub struct Day16;
+
+#[derive(Copy, Clone)]
+pub enum Cell {
+    Empty,
+    RightMirror,
+    LeftMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell::Empty),
+            '/' => Ok(Cell::RightMirror),
+            '\\' => Ok(Cell::LeftMirror),
+            '-' => Ok(Cell::HorizontalSplitter),
+            '|' => Ok(Cell::VerticalSplitter),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Cell {
+    fn translate_direction(&self, direction: &Direction) -> (Direction, Option<Direction>) {
+        match self {
+            Cell::Empty => (*direction, None),
+            Cell::RightMirror => (direction.reflect(Mirror::Slash), None),
+            Cell::LeftMirror => (direction.reflect(Mirror::Backslash), None),
+            Cell::HorizontalSplitter => match direction {
+                Direction::North | Direction::South => (Direction::West, Some(Direction::East)),
+                _ => (*direction, None),
+            }
+            Cell::VerticalSplitter => match direction {
+                Direction::West | Direction::East => (Direction::North, Some(Direction::South)),
+                _ => (*direction, None),
+            }
+        }
+    }
+}
+
+#[allow(unused)]
+fn print_energized(energized: &Grid<bool>) {
+    println!("{}", energized.map(|&energized| if energized { '#' } else { '.' }));
+}
+
+fn energize_grid(grid: &Grid<Cell>, start_coord: Coord, start_direction: Direction) -> Grid<bool> {
+    let beams = bfs([(start_coord, start_direction)], None, |&(coord, direction)| {
+        let (first, second) = grid[coord].translate_direction(&direction);
+        [Some(first), second].into_iter()
+            .flatten()
+            .filter_map(move |direction| {
+                let next = coord.next(direction)?;
+                grid.get_coord(&next).map(|_| (next, direction))
+            })
+    });
+    let mut energized = Grid::new(grid.width(), grid.height());
+    beams.reachable().for_each(|&(coord, _)| energized[coord] = true);
+    energized
+}
+
+fn count_energized_cells(energized: &Grid<bool>) -> usize {
+    energized.positions(|&energized| energized).count()
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+    type Input = Grid<Cell>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::from_input(input.lines(), |c| c.try_into().ok())
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Output1 {
+        count_energized_cells(&energize_grid(grid, Coord::new(0, 0), Direction::East))
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Output2 {
+        let entrypoints = (0..grid.width())
+            .flat_map(|x| {
+                [(0, Direction::South), (grid.height() - 1, Direction::North)].into_iter()
+                    .map(move |(y, direction)| (Coord::new(x, y), direction))
+            })
+            .chain((0..grid.height()).flat_map(|y| {
+                [(0, Direction::East), (grid.width() - 1, Direction::West)].into_iter()
+                    .map(move |(x, direction)| (Coord::new(x, y), direction))
+            }))
+            .collect_vec();
+        entrypoints.into_par_iter()
+            .map(|(coord, direction)| energize_grid(grid, coord, direction))
+            .map(|beams| count_energized_cells(&beams))
+            .max()
+            .expect("Infallible")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day16::part1(&input), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day16::part2(&input), 51);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Coord, Direction, Error, Grid, Solution};
+use crate::search::astar;
+
+pub struct Day17;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Crucible {
+    coord: Coord,
+    direction: Direction,
+    continuous_steps: u8,
+}
+
+/// Least heat loss from the top left to the bottom right corner for a crucible that has to move
+/// at least `min_steps` and at most `max_steps` in a straight line, including before stopping at the end.
+fn least_heat_loss(grid: &Grid<u32>, min_steps: u8, max_steps: u8) -> u32 {
+    let end = Coord::new(grid.width() - 1, grid.height() - 1);
+    let min_heat = grid.iter_coords().map(|(_, &heat)| heat).min().expect("Infallible");
+    let starts = [Direction::East, Direction::South]
+        .map(|direction| Crucible { coord: Coord::new(0, 0), direction, continuous_steps: 0 });
+    let successors = |crucible: &Crucible| {
+        let crucible = *crucible;
+        grid.neighbours_with_direction(crucible.coord)
+            .filter(move |&(direction, _, _)| {
+                if direction == crucible.direction {
+                    crucible.continuous_steps < max_steps
+                } else {
+                    direction != crucible.direction.opposite() && crucible.continuous_steps >= min_steps
+                }
+            })
+            .map(move |(direction, coord, &heat)| {
+                let continuous_steps = if direction == crucible.direction {
+                    crucible.continuous_steps + 1
+                } else {
+                    1
+                };
+                (Crucible { coord, direction, continuous_steps }, heat)
+            })
+    };
+    let heuristic = |crucible: &Crucible| (crucible.coord - end) as u32 * min_heat;
+    let is_end = |crucible: &Crucible| crucible.coord == end && crucible.continuous_steps >= min_steps;
+    astar(starts, successors, heuristic, is_end)
+        .expect("End is unreachable")
+        .cost
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+    type Input = Grid<u32>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::from_input(input.lines(), |c| c.to_digit(10))
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Output1 {
+        least_heat_loss(grid, 1, 3)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Output2 {
+        least_heat_loss(grid, 4, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day17::part1(&input), 102);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day17::part2(&input), 94);
+    }
+
+    #[test]
+    fn ultra_crucible_stops_after_four_steps() {
+        let input = Day17::parse("\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991").expect("Bad input");
+        assert_eq!(Day17::part2(&input), 71);
+    }
+}
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+use crate::{Direction, Error, parse_lines, parse_token, Solution};
+use crate::Direction::*;
+
+pub struct Day18;
+
+#[derive(Copy, Clone)]
+struct Command {
+    direction: Direction,
+    distance: usize,
+}
+
+#[derive(Copy, Clone)]
+pub struct Line {
+    normal_command: Command,
+    color_command: Command,
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.split(' ');
+        let mut next = |what: &str| iter.next().ok_or_else(|| Error::new(format!("Missing {what}")));
+        let direction_string = next("direction")?;
+        let direction = direction_string.parse::<Direction>()
+            .map_err(|err| err.within(s, direction_string))?;
+        let distance = parse_token(s, next("distance")?)?;
+        let color = next("color")?;
+        let color_string = color
+            .strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .filter(|color| color.len() == 6 && color.is_ascii())
+            .ok_or_else(|| Error::new("Expected \"(#rrggbb)\"").within(s, color))?;
+        let color_distance = usize::from_str_radix(&color_string[0..5], 16)
+            .map_err(|err| Error::from(err).within(s, color_string))?;
+        let color_direction = match &color_string[5..6] {
+            "0" => Ok(East),
+            "1" => Ok(South),
+            "2" => Ok(West),
+            "3" => Ok(North),
+            digit => Err(Error::new(format!("Bad direction digit: {digit}")).within(s, &color_string[5..6])),
+        }?;
+        Ok(Line {
+            normal_command: Command {
+                direction,
+                distance,
+            },
+            color_command: Command {
+                direction: color_direction,
+                distance: color_distance,
+            },
+        })
+    }
+}
+
+fn calculate_area<I>(commands: I) -> usize where I: Iterator<Item=Command> {
+    commands
+        .fold((0, 0), |(area, current_y), Command{ direction, distance }| {
+            let distance = distance as isize;
+            match direction {
+                East => (area - distance * current_y, current_y),
+                West => (area + distance * (current_y + 1), current_y),
+                North => (area, current_y - distance),
+                South => (area + distance, current_y + distance),
+            }
+        }).0 as usize + 1
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+    type Input = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, str::parse::<Line>)
+    }
+
+    fn part1(commands: &Self::Input) -> Self::Output1 {
+        calculate_area(commands.iter().map(|Line { normal_command, color_command: _ }| *normal_command))
+    }
+
+    fn part2(commands: &Self::Input) -> Self::Output2 {
+        calculate_area(commands.iter().map(|Line { normal_command: _, color_command }| *color_command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn part1_example() {
+        let input = Day18::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day18::part1(&input), 62);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day18::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day18::part2(&input), 952408144115);
+    }
+}
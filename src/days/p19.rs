@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::iter::Sum;
+use std::ops::{Range, Sub};
+use std::str::FromStr;
+use itertools::Itertools;
+use rangemap::RangeSet;
+use crate::{Error, parse_lines, parse_token, sections, Solution};
+use self::Target::*;
+
+pub struct Day19;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Target {
+    Accept,
+    Reject,
+    Redirect(String),
+}
+
+impl FromStr for Target {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "A" => Accept,
+            "R" => Reject,
+            s => Redirect(s.to_string()),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Variable {
+    Extreme,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+impl FromStr for Variable {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Variable::Extreme),
+            "m" => Ok(Variable::Musical),
+            "a" => Ok(Variable::Aerodynamic),
+            "s" => Ok(Variable::Shiny),
+            _ => Err(Error::new(format!("Unknown variable: {s}")))
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Bound {
+    less_then: bool,
+    bound: u64,
+    variable: Variable,
+    target: Target,
+}
+
+impl Bound {
+    fn check_part(&self, part: &Part) -> bool {
+        if self.less_then {
+            part.select(self.variable) < self.bound
+        } else {
+            part.select(self.variable) > self.bound
+        }
+    }
+
+    fn opposite(mut self) -> Self {
+        self.less_then = !self.less_then;
+        if self.less_then {
+            self.bound += 1;
+        } else {
+            self.bound -= 1;
+        }
+        self
+    }
+}
+
+impl FromStr for Bound {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const BRACKETS: [char; 2] = ['<', '>'];
+        let (rest, target) = s.split_once(':')
+            .ok_or_else(|| Error::new("Missing ':'"))?;
+        let angle_position = rest.find(BRACKETS)
+            .ok_or_else(|| Error::new("Missing '<' or '>'"))?;
+        let less_then = &rest[angle_position..angle_position + 1] == "<";
+        let (variable, bound) = rest.split_once(BRACKETS)
+            .expect("Infallible");
+        let variable = variable.parse::<Variable>()
+            .map_err(|err| err.within(s, variable))?;
+        let bound = parse_token(s, bound)?;
+        let target = target.parse()?;
+        Ok(Bound {
+            less_then,
+            bound,
+            variable,
+            target,
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Workflow {
+    name: String,
+    bounds: Vec<Bound>,
+    default: Target,
+}
+
+impl FromStr for Workflow {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('{') {
+            Some((name, rest)) => {
+                match rest.strip_suffix('}') {
+                    Some(workflow) => {
+                        let mut bounds = workflow.split(',')
+                            .collect_vec();
+                        let default = bounds.pop()
+                            .expect("Infallible")
+                            .parse()?;
+                        let bounds = bounds.into_iter()
+                            .map(|bound| bound.parse::<Bound>().map_err(|err| err.within(s, bound)))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(Workflow {
+                            name: name.to_string(),
+                            bounds,
+                            default,
+                        })
+                    }
+                    _ => Err(Error::new("Missing '}'"))
+                }
+            }
+            _ => Err(Error::new("Missing '{'"))
+        }
+    }
+}
+
+pub struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl Part {
+    fn select(&self, variable: Variable) -> u64 {
+        match variable {
+            Variable::Extreme => self.x,
+            Variable::Musical => self.m,
+            Variable::Aerodynamic => self.a,
+            Variable::Shiny => self.s,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let variables = s.strip_prefix('{')
+            .and_then(|ratings| ratings.strip_suffix('}'))
+            .ok_or_else(|| Error::new("Expected \"{x=..,m=..,a=..,s=..}\""))?
+            .split(',')
+            .try_fold((None, None, None, None), |(x, m, a, shiny), segment| {
+                let (variable, value) = segment.split_once('=')
+                    .ok_or_else(|| Error::new("Expected \"<variable>=<value>\"").within(s, segment))?;
+                let value = parse_token::<u64>(s, value)?;
+                match (variable, x, m, a, shiny) {
+                    ("x", None, ..) => Ok((Some(value), m, a, shiny)),
+                    ("m", _, None, ..) => Ok((x, Some(value), a, shiny)),
+                    ("a", _, _, None, _) => Ok((x, m, Some(value), shiny)),
+                    ("s", .., None) => Ok((x, m, a, Some(value))),
+                    ("x" | "m" | "a" | "s", ..) => {
+                        Err(Error::new(format!("Duplicate variable: {variable}")).within(s, variable))
+                    }
+                    _ => Err(Error::new(format!("Unknown variable: {variable}")).within(s, variable)),
+                }
+            })?;
+        match variables {
+            (Some(x), Some(m), Some(a), Some(s)) => Ok(Part { x, m, a, s }),
+            _ => Err(Error::new("Missing variable"))
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct Configurations {
+    x: RangeSet<u64>,
+    m: RangeSet<u64>,
+    a: RangeSet<u64>,
+    s: RangeSet<u64>,
+}
+
+fn range_len<T>(range_set: &RangeSet<T>) -> T
+    where T: Ord + Clone + Copy + Sub + Sum<<T>::Output> {
+    range_set.iter()
+        .map(|x| {
+            x.end - x.start
+        })
+        .sum::<T>()
+}
+
+fn range_intersection<T>(lhs: &RangeSet<T>, rhs: &Range<T>) -> RangeSet<T>
+    where T: Ord + Clone + Copy {
+    lhs.overlapping(rhs)
+        .filter_map(|range| {
+            let range = range.start.max(rhs.start)..range.end.min(rhs.end);
+            if range.is_empty() {
+                None
+            } else {
+                Some(range)
+            }
+        })
+        .collect()
+}
+
+impl Configurations {
+    fn count_possibilities(self, target: &Target, workflows: &HashMap<String, Workflow>) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            match target {
+                Accept => range_len(&self.x) * range_len(&self.m) * range_len(&self.a) * range_len(&self.s),
+                Reject => 0,
+                Redirect(workflow_name) => {
+                    // parse checks that every redirect names a workflow
+                    let workflow = workflows.get(workflow_name)
+                        .expect("Infallible");
+                    let (count, configuration) = workflow.bounds
+                        .iter()
+                        .cloned()
+                        .fold((0, self), |(mut count, configuration), bound| {
+                            count += configuration.intersection(bound.clone())
+                                .count_possibilities(&bound.target, workflows);
+                            (count, configuration.intersection(bound.opposite()))
+                        });
+                    count + configuration.count_possibilities(&workflow.default, workflows)
+                }
+            }
+        }
+    }
+    fn is_empty(&self) -> bool {
+        self.x.is_empty() && self.m.is_empty() && self.a.is_empty() && self.s.is_empty()
+    }
+    fn intersection(&self, bound: Bound) -> Self {
+        let mut config = self.clone();
+        let range = if bound.less_then {
+            u64::MIN..bound.bound
+        } else {
+            bound.bound + 1..u64::MAX
+        };
+        let variable = match bound.variable {
+            Variable::Extreme => &mut config.x,
+            Variable::Musical => &mut config.m,
+            Variable::Aerodynamic => &mut config.a,
+            Variable::Shiny => &mut config.s,
+        };
+        *variable = range_intersection(variable, &range);
+        config
+    }
+}
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+    type Input = System;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let [workflow_lines, parts] = <[&str; 2]>::try_from(sections(input).collect_vec())
+            .map_err(|_| Error::new("Expected workflows and parts separated by an empty line"))?;
+        let workflows = parse_lines(workflow_lines, str::parse::<Workflow>)
+            .map_err(|err| err.within(input, workflow_lines))?;
+        let names = workflows.iter()
+            .map(|workflow| workflow.name.as_str())
+            .collect::<HashSet<_>>();
+        if !names.contains("in") {
+            return Err(Error::new("Missing workflow \"in\"").within(input, workflow_lines));
+        }
+        for (workflow, line) in workflows.iter().zip(workflow_lines.lines()) {
+            let targets = workflow.bounds.iter()
+                .map(|bound| &bound.target)
+                .chain([&workflow.default]);
+            for target in targets {
+                if let Redirect(name) = target {
+                    if !names.contains(name.as_str()) {
+                        return Err(Error::new(format!("Unknown workflow: {name}")).within(input, line));
+                    }
+                }
+            }
+        }
+        let workflows = workflows.into_iter()
+            .map(|workflow| {
+                (workflow.name.clone(), workflow)
+            })
+            .collect::<HashMap<_, _>>();
+        let parts = parse_lines(parts, str::parse::<Part>)
+            .map_err(|err| err.within(input, parts))?;
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(system: &Self::Input) -> Self::Output1 {
+        system.parts.iter()
+            .filter_map(|part| {
+                let mut current_target = Redirect("in".to_string());
+                while let Redirect(workflow_name) = current_target {
+                    // parse checks that "in" and every redirect name a workflow
+                    let workflow = system.workflows.get(&workflow_name)
+                        .expect("Infallible");
+                    if let Some(target) = workflow.bounds
+                        .iter()
+                        .find(|bound| bound.check_part(part))
+                        .map(|bound| bound.target.clone()) {
+                        current_target = target;
+                    } else {
+                        current_target = workflow.default.clone();
+                    }
+                }
+                if current_target == Accept {
+                    Some(part.x + part.m + part.a + part.s)
+                } else {
+                    None
+                }
+            })
+            .sum::<u64>()
+    }
+
+    fn part2(system: &Self::Input) -> Self::Output2 {
+        let mut initial_range = RangeSet::new();
+        initial_range.insert(1..4001);
+        Configurations {
+            x: initial_range.clone(),
+            m: initial_range.clone(),
+            a: initial_range.clone(),
+            s: initial_range.clone(),
+        }
+            .count_possibilities(&Redirect("in".to_string()), &system.workflows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day19::part1(&input), 19114);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day19::part2(&input), 167409079868000);
+    }
+
+    #[test]
+    fn redirects_need_a_workflow() {
+        let err = Day19::parse("in{x<10:foo,A}\n\n{x=1,m=2,a=3,s=4}").err().map(|err| err.to_string());
+        assert_eq!(err.as_deref(), Some("line 1, column 1: Unknown workflow: foo"));
+        let err = Day19::parse("in{x<10:R,px}\npx{a>5:A,bar}\n\n{x=1,m=2,a=3,s=4}").err().map(|err| err.to_string());
+        assert_eq!(err.as_deref(), Some("line 2, column 1: Unknown workflow: bar"));
+        let err = Day19::parse("px{x<10:R,A}\n\n{x=1,m=2,a=3,s=4}").err().map(|err| err.to_string());
+        assert_eq!(err.as_deref(), Some("line 1, column 1: Missing workflow \"in\""));
+    }
+}
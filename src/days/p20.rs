@@ -0,0 +1,192 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+use itertools::Itertools;
+use num::integer::lcm;
+use smol_str::SmolStr;
+use crate::{Error, parse_lines, Solution};
+use self::ModuleType::{Broadcaster, Conjunction, FlipFlop};
+
+pub struct Day20;
+
+#[derive(Clone, Debug)]
+enum ModuleType {
+    FlipFlop(bool),
+    Conjunction(HashMap<SmolStr, bool>),
+    Broadcaster,
+}
+
+#[derive(Clone, Debug)]
+pub struct Module {
+    name: SmolStr,
+    module_type: ModuleType,
+    targets: Vec<SmolStr>,
+}
+
+impl FromStr for Module {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, targets) = s.split_once("->")
+            .ok_or_else(|| Error::new("Missing \"->\""))?;
+        let name = name.trim();
+        let (module_type, name) = if let Some(name) = name.strip_prefix('%') {
+            (FlipFlop(false), name)
+        } else if let Some(name) = name.strip_prefix('&') {
+            (Conjunction(HashMap::new()), name)
+        } else if name == "broadcaster" {
+            (Broadcaster, name)
+        } else {
+            return Err(Error::new(format!("Unknown module type: {name}")).within(s, name));
+        };
+        let name = name.into();
+        let targets = targets.split(',')
+            .map(str::trim)
+            .map(<&str as Into<SmolStr>>::into)
+            .collect_vec();
+        Ok(Self {
+            name,
+            module_type,
+            targets,
+        })
+    }
+}
+
+fn press_button<F>(modules: &mut HashMap<SmolStr, Module>, mut on_pulse: F) where F: FnMut(&SmolStr, &SmolStr, bool) {
+    let broadcaster_name = SmolStr::new("broadcaster");
+    let mut pulses = VecDeque::new();
+    pulses.push_back((broadcaster_name.clone(), broadcaster_name, false));
+    while let Some((pulse_source, pulse_target, pulse_high)) = pulses.pop_front() {
+        on_pulse(&pulse_source, &pulse_target, pulse_high);
+        if let Some(target) = modules.get_mut(&pulse_target) {
+            match &mut target.module_type {
+                FlipFlop(internal_state) => {
+                    if !pulse_high {
+                        *internal_state = !*internal_state;
+                        for target in &target.targets {
+                            pulses.push_back((pulse_target.clone(), target.clone(), *internal_state));
+                        }
+                    }
+                }
+                Conjunction(inputs) => {
+                    // parse registers every module sending to a conjunction as one of its inputs
+                    *inputs.get_mut(&pulse_source).expect("Infallible") = pulse_high;
+                    let next_pulse_high = !inputs.iter().all(|(_, &high)| high);
+                    for target in &target.targets {
+                        pulses.push_back((pulse_target.clone(), target.clone(), next_pulse_high));
+                    }
+                }
+                Broadcaster => {
+                    for target in &target.targets {
+                        pulses.push_back((pulse_target.clone(), target.clone(), pulse_high));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+    type Input = HashMap<SmolStr, Module>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let modules = parse_lines(input, str::parse::<Module>)?;
+        let mut modules = modules.into_iter()
+            .map(|module| {
+                (module.name.clone(), module)
+            })
+            .collect::<HashMap<_, _>>();
+        let modules_ref = modules.clone();
+        for (name, module) in modules.iter_mut() {
+            if let Conjunction(ref mut inputs) = &mut module.module_type {
+                *inputs = modules_ref.iter()
+                    .filter_map(|(input_name, Module { targets, .. })| {
+                        if targets.contains(name) {
+                            Some((input_name.clone(), false))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+            }
+        }
+        Ok(modules)
+    }
+
+    fn part1(modules: &Self::Input) -> Self::Output1 {
+        let mut modules = modules.clone();
+        let mut high_pulse_count = 0u64;
+        let mut low_pulse_count = 0u64;
+        for _ in 0..1000 {
+            press_button(&mut modules, |_, _, pulse_high| {
+                if pulse_high {
+                    high_pulse_count += 1;
+                } else {
+                    low_pulse_count += 1;
+                }
+            });
+        }
+        high_pulse_count * low_pulse_count
+    }
+
+    fn part2(modules: &Self::Input) -> Self::Output2 {
+        let mut modules = modules.clone();
+        let final_module = SmolStr::new("rx");
+        let (rx_parent, rx_parent_module) = modules.iter()
+            .find(|&(_, module)| {
+                module.targets.contains(&final_module)
+            })
+            .expect("Rx has no input");
+        let rx_parent = rx_parent.clone();
+        let rx_grandparent_count = match &rx_parent_module.module_type {
+            Conjunction(inputs) => inputs.len(),
+            _ => panic!("Rx's is not a conjunction"),
+        };
+        let mut rx_grandparents = HashMap::<SmolStr, u64>::new();
+        for button_press in 1.. {
+            press_button(&mut modules, |pulse_source, pulse_target, pulse_high| {
+                if *pulse_target == rx_parent && pulse_high {
+                    rx_grandparents.entry(pulse_source.clone()).or_insert(button_press);
+                }
+            });
+            if rx_grandparent_count == rx_grandparents.len() {
+                break;
+            }
+        }
+        rx_grandparents.into_values()
+            .fold(1, lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+    const EXAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn part1_example_1() {
+        let input = Day20::parse(EXAMPLE_1).expect("Bad input");
+        assert_eq!(Day20::part1(&input), 32000000);
+    }
+
+    #[test]
+    fn part1_example_2() {
+        let input = Day20::parse(EXAMPLE_2).expect("Bad input");
+        assert_eq!(Day20::part1(&input), 11687500);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Coord, Error, Grid, ICoord, some, Solution};
+use crate::search::{bfs, bfs_grid, Reached};
+
+pub struct Day21;
+
+fn bfs_bounded(grid: &Grid<bool>, start: Coord, step_limit: usize) -> Reached<Coord> {
+    bfs_grid(grid, [start], Some(step_limit), |&open| open)
+}
+
+fn bfs_wrapping(grid: &Grid<bool>, start: Coord, step_limit: usize) -> Reached<ICoord> {
+    let wrapping = grid.wrapping();
+    bfs([start.into()], Some(step_limit), |cell: &ICoord| {
+        cell.orthogonal_neighbours()
+            .into_iter()
+            .filter(move |&next_cell| wrapping[next_cell])
+    })
+}
+
+pub struct Garden {
+    /// Whether each plot can be stepped on.
+    grid: Grid<bool>,
+    start: Coord,
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
+    type Input = Garden;
+    type Output1 = usize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let grid = Grid::from_input(input.lines(), some)?;
+        let start = grid.position(|&c| c == 'S')
+            .ok_or_else(|| Error::new("No start tile"))?;
+        let grid = grid.map(|&c| c != '#');
+        Ok(Garden { grid, start })
+    }
+
+    fn part1(garden: &Self::Input) -> Self::Output1 {
+        let step_limit_1 = 64;
+        let visited = bfs_bounded(&garden.grid, garden.start, step_limit_1);
+        visited.count_with_parity(step_limit_1)
+    }
+
+    fn part2(garden: &Self::Input) -> Self::Output2 {
+        let Garden { grid, start } = garden;
+        let step_limit_2 = 26501365;
+        let visited = bfs_wrapping(grid, *start, start.x + grid.width() * 2);
+        let visited_0 = visited.count_with_parity(start.x) as isize;
+        let visited_1 = visited.count_with_parity(start.x + grid.width()) as isize;
+        let visited_2 = visited.count_with_parity(start.x + grid.width() * 2) as isize;
+        let count_filled_maps = ((step_limit_2 - grid.width() / 2) / grid.width()) as isize;
+        let det_a_0 = -visited_0 + 2 * visited_1 - visited_2;
+        let det_a_1 = 3 * visited_0 - 4 * visited_1 + visited_2;
+        let det_a_2 = -2 * visited_0;
+        let x_0 = det_a_0 / -2;
+        let x_1 = det_a_1 / -2;
+        let x_2 = det_a_2 / -2;
+        x_0 * count_filled_maps * count_filled_maps + x_1 * count_filled_maps + x_2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn part1_example() {
+        let garden = Day21::parse(EXAMPLE).expect("Bad input");
+        let visited = bfs_bounded(&garden.grid, garden.start, 6);
+        assert_eq!(visited.count_with_parity(6), 16);
+    }
+
+    #[test]
+    fn infinite_garden_example() {
+        let garden = Day21::parse(EXAMPLE).expect("Bad input");
+        let visited = bfs_wrapping(&garden.grid, garden.start, 100);
+        assert_eq!(visited.count_with_parity(6), 16);
+        assert_eq!(visited.count_with_parity(10), 50);
+        assert_eq!(visited.count_with_parity(50), 1594);
+        assert_eq!(visited.count_with_parity(100), 6536);
+    }
+}
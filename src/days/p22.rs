@@ -0,0 +1,237 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::str::FromStr;
+use itertools::Itertools;
+use crate::{Coord, Coord3, Error, Grid3, parse_lines, Solution};
+
+pub struct Day22;
+
+#[derive(Copy, Clone, Default, Hash, Debug, Ord, PartialOrd, Eq, PartialEq)]
+struct Brick {
+    min_corner: Coord3,
+    max_corner: Coord3,
+}
+
+impl Brick {
+    fn new(lhs: Coord3, rhs: Coord3) -> Self {
+        Self {
+            min_corner: lhs.minimum(&rhs),
+            max_corner: lhs.maximum(&rhs),
+        }
+    }
+    #[allow(unused)]
+    fn width(&self) -> usize {
+        self.max_corner.x - self.min_corner.x + 1
+    }
+    #[allow(unused)]
+    fn depth(&self) -> usize {
+        self.max_corner.y - self.min_corner.y + 1
+    }
+    fn height(&self) -> usize {
+        self.max_corner.z - self.min_corner.z + 1
+    }
+    #[allow(unused)]
+    fn size(&self) -> Coord3 {
+        Coord3 {
+            x: self.width(),
+            y: self.depth(),
+            z: self.height(),
+        }
+    }
+    fn descend(&mut self, distance: usize) {
+        self.min_corner.z -= distance;
+        self.max_corner.z -= distance;
+    }
+}
+
+impl FromStr for Brick {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lhs, rhs) = s.split_once('~')
+            .ok_or_else(|| Error::new("Missing '~'"))?;
+        let brick = Brick::new(
+            lhs.parse().map_err(|err: Error| err.within(s, lhs))?,
+            rhs.parse().map_err(|err: Error| err.within(s, rhs))?,
+        );
+        if (brick.min_corner.x != brick.max_corner.x) as i32 +
+            (brick.min_corner.y != brick.max_corner.y) as i32 +
+            (brick.min_corner.z != brick.max_corner.z) as i32 > 1 {
+            Err(Error::new("Brick is not a straight line"))
+        } else {
+            Ok(brick)
+        }
+    }
+}
+
+#[allow(unused)]
+fn print_occupancy_grid(grid3d: &Grid3<Option<usize>>) {
+    print!("{}", grid3d.map(|cell| match cell {
+        None => "  .  ".to_string(),
+        Some(idx) => format!("{idx:^5}"),
+    }));
+}
+
+fn push_supported_bricks_to_queue(queue: &mut BinaryHeap<(usize, usize)>, bricks: &[Brick], deleted_brick_idx: usize, supported_bricks: &[HashSet<usize>]) {
+    supported_bricks[deleted_brick_idx].iter()
+        .for_each(|&supported_brick_idx| {
+            queue.push((bricks[supported_brick_idx].min_corner.z, supported_brick_idx));
+        });
+}
+
+pub struct Tower {
+    bricks: Vec<Brick>,
+    supporting_bricks: Vec<HashSet<usize>>,
+    unmovable_bricks: Vec<usize>,
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Sand Slabs";
+    type Input = Tower;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut bricks = parse_lines(input, str::parse::<Brick>)?
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+        let mut occupancy_grid3d = Grid3::<Option<usize>>::covering(bricks.iter().map(|brick| brick.max_corner));
+        for (idx, brick) in bricks.iter_mut().enumerate() {
+            let level_coords = (brick.min_corner.x..=brick.max_corner.x)
+                .cartesian_product(brick.min_corner.y..=brick.max_corner.y)
+                .map(|(x, y)| Coord::new(x, y))
+                .collect_vec();
+            let bottom_free_z = (0..=brick.min_corner.z).rev()
+                .find(|&z| {
+                    level_coords.iter()
+                        .any(|coord| {
+                            occupancy_grid3d.layer(z)[*coord].is_some()
+                        })
+                })
+                .map(|z| z + 1)
+                .unwrap_or(0);
+            (bottom_free_z..bottom_free_z + brick.height())
+                .for_each(|z| {
+                    level_coords.iter()
+                        .for_each(|coord| {
+                            occupancy_grid3d.layer_mut(z)[*coord] = Some(idx)
+                        })
+                });
+            brick.descend(brick.min_corner.z - bottom_free_z);
+        }
+        bricks.sort();
+        let mut supporting_bricks = vec![HashSet::new(); bricks.len()];
+        occupancy_grid3d.layers()
+            .tuple_windows()
+            .for_each(|(bottom, top)| {
+                bottom.iter()
+                    .zip(top.iter())
+                    .for_each(|(bottom_row, top_row)| {
+                        bottom_row.iter()
+                            .zip(top_row.iter())
+                            .for_each(|(bottom_cell, top_cell)| {
+                                if let (Some(bottom_idx), Some(top_idx)) = (bottom_cell, top_cell) {
+                                    if bottom_idx != top_idx {
+                                        supporting_bricks[*top_idx].insert(*bottom_idx);
+                                    }
+                                }
+                            });
+                    });
+            });
+        let unmovable_bricks = supporting_bricks.iter()
+            .filter_map(|supports| {
+                if supports.len() == 1 {
+                    Some(*supports.iter().next().expect("Infallible"))
+                } else {
+                    None
+                }
+            })
+            .unique()
+            .collect_vec();
+        Ok(Tower { bricks, supporting_bricks, unmovable_bricks })
+    }
+
+    fn part1(tower: &Self::Input) -> Self::Output1 {
+        tower.bricks.len() - tower.unmovable_bricks.len()
+    }
+
+    fn part2(tower: &Self::Input) -> Self::Output2 {
+        let Tower { bricks, supporting_bricks, unmovable_bricks } = tower;
+        let mut supported_bricks = vec![HashSet::new(); bricks.len()];
+        supporting_bricks.iter()
+            .enumerate()
+            .for_each(|(supported_idx, supports)| {
+                supports.iter()
+                    .for_each(|support_idx| {
+                        supported_bricks[*support_idx].insert(supported_idx);
+                    })
+            });
+        let mut chain_reaction_cache = HashMap::new();
+        unmovable_bricks.iter()
+            .sorted()
+            .rev()
+            .map(|&unmovable_brick_idx| {
+                let mut deleted_bricks = HashSet::new();
+                deleted_bricks.insert(unmovable_brick_idx);
+                let mut chain_reaction_queue = BinaryHeap::new();
+                push_supported_bricks_to_queue(&mut chain_reaction_queue, bricks, unmovable_brick_idx, &supported_bricks);
+                while let Some((_, supported_brick_idx)) = chain_reaction_queue.pop() {
+                    if supporting_bricks[supported_brick_idx].iter()
+                        .all(|supporting_brick_idx| deleted_bricks.contains(supporting_brick_idx)) {
+                        match chain_reaction_cache.get(&supported_brick_idx) {
+                            Some(already_deleted_bricks) => {
+                                deleted_bricks.extend(already_deleted_bricks);
+                            }
+                            None => {
+                                deleted_bricks.insert(supported_brick_idx);
+                                push_supported_bricks_to_queue(&mut chain_reaction_queue, bricks, supported_brick_idx, &supported_bricks);
+                            }
+                        }
+                    }
+                }
+                let deleted_count = deleted_bricks.len() - 1;
+                chain_reaction_cache.insert(unmovable_brick_idx, deleted_bricks);
+                deleted_count
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day22::part1(&input), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day22::part2(&input), 7);
+    }
+
+    #[test]
+    fn bricks_settle_from_the_bottom_up() {
+        let low = "2,0,1~2,0,1".parse::<Brick>().expect("Bad brick");
+        let high = "0,0,5~2,0,5".parse::<Brick>().expect("Bad brick");
+        // Ordered by height first, although the high brick starts at a smaller x
+        assert!(low < high);
+        let tower = Day22::parse("0,0,5~2,0,5\n2,0,1~2,0,1").expect("Bad input");
+        assert_eq!(tower.bricks[0].min_corner, Coord3::new(2, 0, 0));
+        assert_eq!(tower.bricks[1].min_corner, Coord3::new(0, 0, 1));
+        assert_eq!(tower.supporting_bricks[1], HashSet::from([0]));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Direction, Error, Grid, some, xy, Solution};
+use crate::graph::{contract_corridors, Junctions};
+
+pub struct Day23;
+
+pub struct Trails {
+    slippery: Junctions,
+    dry: Junctions,
+    start: usize,
+    end: usize,
+}
+
+fn longest_hike(junctions: &Junctions, start: usize, end: usize) -> usize {
+    junctions.graph.longest_path_par(start, end)
+        .expect("End is unreachable") as usize
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
+    type Input = Trails;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let grid = Grid::from_input(input.lines(), some)?;
+        let start = xy!(1, 0);
+        let end = xy!(grid.width() - 2, grid.height() - 1);
+        let passable = |&c: &char| c != '#';
+        let slippery = contract_corridors(&grid, [start, end], passable, |&c| Direction::from_arrow(c));
+        let dry = contract_corridors(&grid, [start, end], passable, |_| None);
+        // Both graphs share their nodes, slopes only remove edges
+        let (start, end) = (slippery.id(&start).expect("Infallible"), slippery.id(&end).expect("Infallible"));
+        Ok(Trails { slippery, dry, start, end })
+    }
+
+    fn part1(trails: &Self::Input) -> Self::Output1 {
+        longest_hike(&trails.slippery, trails.start, trails.end)
+    }
+
+    fn part2(trails: &Self::Input) -> Self::Output2 {
+        longest_hike(&trails.dry, trails.start, trails.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###v#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day23::part1(&input), 94);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day23::part2(&input), 154);
+    }
+}
@@ -0,0 +1,181 @@
+use itertools::Itertools;
+use num::{BigInt, BigRational, One, Signed, ToPrimitive, Zero};
+use num::rational::Ratio;
+use crate::{Error, parse_lines, parse_token, Solution};
+
+pub struct Day24;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3],
+}
+
+fn parse_vector(line: &str, vector: &str) -> Result<[i64; 3], Error> {
+    vector.split(',')
+        .map(|n| parse_token(line, n.trim()))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| Error::new("Expected 3 coordinates").within(line, vector))
+}
+
+fn count_intersections(hailstones: &[Hailstone], area_min: i64, area_max: i64) -> usize {
+    let in_area = |n: Ratio<i128>| Ratio::from(area_min as i128) <= n && n <= Ratio::from(area_max as i128);
+    hailstones.iter()
+        .tuple_combinations()
+        .filter(|(lhs, rhs)| {
+            let [p1x, p1y, _] = lhs.position.map(i128::from);
+            let [v1x, v1y, _] = lhs.velocity.map(i128::from);
+            let [p2x, p2y, _] = rhs.position.map(i128::from);
+            let [v2x, v2y, _] = rhs.velocity.map(i128::from);
+            // p1 + v1 * t = p2 + v2 * s solved with Cramer's rule, parallel paths never cross
+            let det = v2x * v1y - v1x * v2y;
+            if det == 0 {
+                return false;
+            }
+            let (dx, dy) = (p2x - p1x, p2y - p1y);
+            let t = Ratio::new(v2x * dy - v2y * dx, det);
+            let s = Ratio::new(v1x * dy - v1y * dx, det);
+            t.is_positive() && s.is_positive() &&
+                in_area(Ratio::from(p1x) + Ratio::from(v1x) * t) &&
+                in_area(Ratio::from(p1y) + Ratio::from(v1y) * t)
+        })
+        .count()
+}
+
+fn solve_linear(mut matrix: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let size = matrix.len();
+    for col in 0..size {
+        let pivot = (col..size).find(|&row| !matrix[row][col].is_zero())?;
+        matrix.swap(col, pivot);
+        let pivot_row = matrix[col].clone();
+        for (row, values) in matrix.iter_mut().enumerate() {
+            if row != col && !values[col].is_zero() {
+                let factor = &values[col] / &pivot_row[col];
+                values.iter_mut()
+                    .zip(&pivot_row)
+                    .for_each(|(value, pivot_value)| *value -= &factor * pivot_value);
+            }
+        }
+    }
+    Some(matrix.iter()
+        .enumerate()
+        .map(|(row, values)| &values[size] / &values[row])
+        .collect())
+}
+
+fn cross(a: [i64; 3], b: [i64; 3]) -> [BigInt; 3] {
+    let [a, b] = [a, b].map(|v| v.map(BigInt::from));
+    [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ]
+}
+
+fn sub(a: [i64; 3], b: [i64; 3]) -> [i64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// Rock position and velocity hitting all three hailstones, `None` when they do not pin it down.
+///
+/// For every hailstone `(P - p) × (V - v) = 0`, subtracting the equations of two hailstones
+/// cancels the `P × V` term, so two pairs give six linear equations in `P` and `V`.
+fn rock_throw(hailstones: [&Hailstone; 3]) -> Option<[BigRational; 6]> {
+    let [first, rest @ ..] = hailstones;
+    let matrix = rest.iter()
+        .flat_map(|other| {
+            let [wx, wy, wz] = sub(other.velocity, first.velocity);
+            let [dx, dy, dz] = sub(other.position, first.position);
+            let rhs = cross(other.position, other.velocity)
+                .into_iter()
+                .zip(cross(first.position, first.velocity))
+                .map(|(lhs, rhs)| lhs - rhs)
+                .collect_vec();
+            // P × w + d × V = p_j × v_j - p_i × v_i, with P and V as the unknowns
+            [
+                [0, wz, -wy, 0, -dz, dy],
+                [-wz, 0, wx, dz, 0, -dx],
+                [wy, -wx, 0, -dy, dx, 0],
+            ]
+                .into_iter()
+                .zip(rhs)
+                .map(|(coefficients, rhs)| {
+                    coefficients.into_iter()
+                        .map(|n| BigRational::from_integer(n.into()))
+                        .chain([BigRational::from_integer(rhs)])
+                        .collect_vec()
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    solve_linear(matrix)?.try_into().ok()
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+    type Input = Vec<Hailstone>;
+    type Output1 = usize;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let hailstones = parse_lines(input, |line| {
+            let (position, velocity) = line.split_once('@')
+                .ok_or_else(|| Error::new("Missing '@'"))?;
+            Ok(Hailstone {
+                position: parse_vector(line, position)?,
+                velocity: parse_vector(line, velocity)?,
+            })
+        })?;
+        if hailstones.len() < 3 {
+            return Err(Error::new("Expected at least 3 hailstones"));
+        }
+        Ok(hailstones)
+    }
+
+    fn part1(hailstones: &Self::Input) -> Self::Output1 {
+        count_intersections(hailstones, 200000000000000, 400000000000000)
+    }
+
+    fn part2(hailstones: &Self::Input) -> Self::Output2 {
+        let rock = hailstones.iter()
+            .tuple_combinations()
+            .find_map(|(a, b, c)| rock_throw([a, b, c]))
+            // parse checks there are three hailstones, so this only fails when no single rock hits them all
+            .expect("Hailstones do not pin down the rock");
+        let position = &rock[0] + &rock[1] + &rock[2];
+        assert!(position.denom().is_one(), "Rock position is not integral");
+        position.to_integer().to_i64().expect("Rock position out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(count_intersections(&input, 7, 27), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day24::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day24::part2(&input), 47);
+    }
+
+    #[test]
+    fn needs_three_hailstones() {
+        let err = Day24::parse("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2").err().map(|err| err.to_string());
+        assert_eq!(err.as_deref(), Some("Expected at least 3 hailstones"));
+    }
+}
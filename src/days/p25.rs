@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use crate::{Error, Solution};
+use crate::graph::Graph;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Snowverload";
+    type Input = Graph;
+    type Output1 = usize;
+    type Output2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut graph = Graph::default();
+        let mut ids = HashMap::new();
+        let mut node_id = |graph: &mut Graph, name| *ids.entry(name).or_insert_with(|| graph.add_node());
+        for line in input.lines() {
+            let (name, connections) = line.split_once(':')
+                .ok_or_else(|| Error::new("Missing ':'").within(input, line))?;
+            let name = name.trim();
+            let node = node_id(&mut graph, name);
+            for connection in connections.split_whitespace() {
+                if connection == name {
+                    return Err(Error::new("Component connected to itself").within(input, connection));
+                }
+                let other = node_id(&mut graph, connection);
+                graph.add_edge(node, other, 1);
+            }
+        }
+        if graph.node_count() < 2 {
+            return Err(Error::new("Expected at least two components"));
+        }
+        Ok(graph)
+    }
+
+    fn part1(graph: &Self::Input) -> Self::Output1 {
+        // parse checks there are two nodes to cut between
+        let cut = graph.min_cut().expect("Infallible");
+        assert_eq!(cut.weight, 3, "Expected exactly three wires to cut");
+        cut.partition.len() * (graph.node_count() - cut.partition.len())
+    }
+
+    fn part2(_: &Self::Input) -> Self::Output2 {
+        // There is no second puzzle on the last day
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).expect("Bad input");
+        assert_eq!(Day25::part1(&input), 54);
+    }
+
+    #[test]
+    fn needs_two_components() {
+        assert_eq!(Day25::parse("").err().map(|err| err.to_string()).as_deref(), Some("Expected at least two components"));
+    }
+}
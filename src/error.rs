@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+use std::convert::Infallible;
+use std::num::ParseIntError;
+
+/// Input error with a 1-based location relative to the text that was being parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub reason: String,
+}
+
+impl Error {
+    pub fn new<S>(reason: S) -> Self where S: Into<String> {
+        Self { line: None, column: None, reason: reason.into() }
+    }
+
+    /// Translates the location from `inner` to `outer`, where `inner` must be a subslice of `outer`.
+    /// Without a location yet, the error points at the start of `inner`.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let Some(offset) = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)
+            .filter(|&offset| offset + inner.len() <= outer.len()) else {
+            return self;
+        };
+        let prefix = &outer[..offset];
+        let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+        let inner_line = self.line.unwrap_or(1);
+        let column = if inner_line == 1 {
+            Some(offset - line_start + self.column.unwrap_or(1))
+        } else {
+            self.column
+        };
+        Self {
+            line: Some(prefix.matches('\n').count() + inner_line),
+            column,
+            reason: self.reason,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: {}", self.reason),
+            (Some(line), None) => write!(f, "line {line}: {}", self.reason),
+            (None, Some(column)) => write!(f, "column {column}: {}", self.reason),
+            (None, None) => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Infallible> for Error {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::new(format!("Bad number: {err}"))
+    }
+}
+
+/// Parses every line of `input`, locating errors within the whole input.
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(&str) -> Result<T, Error>,
+{
+    input.lines()
+        .map(|line| parse(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+/// Parses `token`, a subslice of `outer`, pointing at it on failure.
+pub fn parse_token<T>(outer: &str, token: &str) -> Result<T, Error> where T: std::str::FromStr {
+    token.parse()
+        .map_err(|_| Error::new(format!("Bad value: {token:?}")).within(outer, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_points_at_inner_start() {
+        let input = "first\nsecond third";
+        let token = &input[13..];
+        assert_eq!(Error::new("Bad").within(input, token).to_string(), "line 2, column 8: Bad");
+    }
+
+    #[test]
+    fn within_nests() {
+        let input = "a\nb: 1, x";
+        let line = input.lines().nth(1).expect("Infallible");
+        let rest = &line[3..];
+        let err = parse_token::<u32>(rest, &rest[3..]).expect_err("Not a number")
+            .within(line, rest)
+            .within(input, line);
+        assert_eq!((err.line, err.column), (Some(2), Some(7)));
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        let err = parse_lines(" 1\n2\nthree", |line| parse_token::<u32>(line, line.trim()))
+            .expect_err("Not a number");
+        assert_eq!(err.to_string(), "line 3, column 1: Bad value: \"three\"");
+    }
+}
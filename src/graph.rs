@@ -0,0 +1,501 @@
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use rayon::prelude::*;
+use crate::{Coord, Direction, Grid};
+
+/// Undirected weighted graph over dense node ids; parallel edges are merged by adding their weights.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Graph {
+    adjacency: Vec<HashMap<usize, u64>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MinCut {
+    pub weight: u64,
+    /// Nodes on one side of the cut, the rest of the graph is on the other side.
+    pub partition: Vec<usize>,
+}
+
+impl Graph {
+    pub fn new(node_count: usize) -> Self {
+        Self { adjacency: vec![HashMap::new(); node_count] }
+    }
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(HashMap::new());
+        self.adjacency.len() - 1
+    }
+    pub fn add_edge(&mut self, a: usize, b: usize, weight: u64) {
+        assert_ne!(a, b, "Self loops are not supported");
+        *self.adjacency[a].entry(b).or_default() += weight;
+        *self.adjacency[b].entry(a).or_default() += weight;
+    }
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(HashMap::len).sum::<usize>() / 2
+    }
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item=(usize, u64)> + '_ {
+        self.adjacency[node].iter().map(|(&neighbour, &weight)| (neighbour, weight))
+    }
+
+    /// Global minimum cut using Stoer–Wagner, `None` for graphs with fewer than two nodes.
+    pub fn min_cut(&self) -> Option<MinCut> {
+        let node_count = self.node_count();
+        if node_count < 2 {
+            return None;
+        }
+        let mut adjacency = self.adjacency.clone();
+        let mut members = (0..node_count).map(|node| vec![node]).collect::<Vec<_>>();
+        let mut active = (0..node_count).collect::<Vec<_>>();
+        let mut best: Option<MinCut> = None;
+        while active.len() > 1 {
+            // Maximum adjacency ordering, the last two nodes are the ones merged after the phase
+            let mut weights = vec![0u64; node_count];
+            let mut added = vec![false; node_count];
+            let mut queue = BinaryHeap::from([(0, active[0])]);
+            let mut added_count = 0;
+            let (mut previous, mut last, mut cut_weight) = (active[0], active[0], 0);
+            while let Some((weight, node)) = queue.pop() {
+                if added[node] || weight != weights[node] {
+                    continue;
+                }
+                added[node] = true;
+                added_count += 1;
+                (previous, last, cut_weight) = (last, node, weight);
+                for (&neighbour, &edge_weight) in &adjacency[node] {
+                    if !added[neighbour] {
+                        weights[neighbour] += edge_weight;
+                        queue.push((weights[neighbour], neighbour));
+                    }
+                }
+            }
+            if added_count < active.len() {
+                let partition = active.iter()
+                    .filter(|&&node| added[node])
+                    .flat_map(|&node| members[node].iter().copied())
+                    .collect();
+                return Some(MinCut { weight: 0, partition });
+            }
+            if best.as_ref().is_none_or(|best| cut_weight < best.weight) {
+                best = Some(MinCut { weight: cut_weight, partition: members[last].clone() });
+            }
+            let merged = std::mem::take(&mut adjacency[last]);
+            for (neighbour, weight) in merged {
+                adjacency[neighbour].remove(&last);
+                if neighbour != previous {
+                    *adjacency[previous].entry(neighbour).or_default() += weight;
+                    *adjacency[neighbour].entry(previous).or_default() += weight;
+                }
+            }
+            let merged_members = std::mem::take(&mut members[last]);
+            members[previous].extend(merged_members);
+            active.retain(|&node| node != last);
+        }
+        best
+    }
+}
+
+/// Directed weighted graph over dense node ids, parallel edges are kept as they are.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct DiGraph {
+    adjacency: Vec<Vec<(usize, u64)>>,
+}
+
+impl DiGraph {
+    pub fn new(node_count: usize) -> Self {
+        Self { adjacency: vec![vec![]; node_count] }
+    }
+    pub fn add_node(&mut self) -> usize {
+        self.adjacency.push(vec![]);
+        self.adjacency.len() - 1
+    }
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        self.adjacency[from].push((to, weight));
+    }
+    /// Adds the edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: u64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item=(usize, u64)> + '_ {
+        self.adjacency[node].iter().copied()
+    }
+
+    /// Weight of the heaviest path from `start` to `end` that visits no node twice, `None` when
+    /// `end` is unreachable. Exhaustive, so only suited to small graphs like contracted mazes.
+    pub fn longest_path(&self, start: usize, end: usize) -> Option<u64> {
+        LongestPath::new(self, start, end).run(false)
+    }
+    /// Like [`DiGraph::longest_path`], exploring the first branches in parallel.
+    pub fn longest_path_par(&self, start: usize, end: usize) -> Option<u64> {
+        LongestPath::new(self, start, end).run(true)
+    }
+}
+
+/// Maze contracted by [`contract_corridors`], node ids mapping back to the cells they stand for.
+#[derive(Debug, Clone)]
+pub struct Junctions {
+    pub graph: DiGraph,
+    coords: Vec<Coord>,
+    ids: HashMap<Coord, usize>,
+}
+
+impl Junctions {
+    pub fn id(&self, coord: &Coord) -> Option<usize> {
+        self.ids.get(coord).copied()
+    }
+    pub fn coord(&self, id: usize) -> Coord {
+        self.coords[id]
+    }
+    /// Cells of all nodes, indexed by node id.
+    pub fn coords(&self) -> &[Coord] {
+        &self.coords
+    }
+}
+
+/// Contracts the single-width corridors of `grid` into weighted edges between junctions.
+///
+/// Nodes are the `passable` cells without exactly two passable neighbours, plus `extra_nodes`,
+/// numbered in reading order. A cell for which `one_way` returns a direction cannot be entered
+/// moving against it, so corridors through it only get an edge one way. Corridors that loop
+/// back to where they started and rings without any junction are left out, also when an
+/// impassable extra node touches the ring.
+pub fn contract_corridors<T, I, P, O>(grid: &Grid<T>, extra_nodes: I, passable: P, one_way: O) -> Junctions
+    where
+        I: IntoIterator<Item=Coord>,
+        P: Fn(&T) -> bool,
+        O: Fn(&T) -> Option<Direction>,
+{
+    let open_neighbours = |coord: Coord| grid.neighbours_with_direction(coord)
+        .filter(|(_, _, value)| passable(value))
+        .map(|(direction, neighbour, value)| (direction, neighbour, one_way(value)));
+    let mut nodes = grid.iter_coords()
+        .filter(|&(coord, value)| passable(value) && open_neighbours(coord).count() != 2)
+        .map(|(coord, _)| coord)
+        .collect::<Vec<_>>();
+    nodes.extend(extra_nodes);
+    nodes.sort_by_key(|coord| (coord.y, coord.x));
+    nodes.dedup();
+    let ids = nodes.iter()
+        .enumerate()
+        .map(|(id, &coord)| (coord, id))
+        .collect::<HashMap<_, _>>();
+    let mut graph = DiGraph::new(nodes.len());
+    for (id, &node) in nodes.iter().enumerate() {
+        for (mut direction, first, mut slope) in open_neighbours(node) {
+            let (mut cell, mut length) = (first, 1);
+            let mut open = slope != Some(direction.opposite());
+            while !ids.contains_key(&cell) {
+                // Not a node, so there is exactly one way on
+                (direction, cell, slope) = open_neighbours(cell)
+                    .find(|&(step, _, _)| step != direction.opposite())
+                    .expect("Infallible");
+                length += 1;
+                open &= slope != Some(direction.opposite());
+                // Corridors cannot branch, so a walk that does not end in a node comes back to its first cell
+                if cell == first {
+                    open = false;
+                    break;
+                }
+            }
+            if let Some(&to) = ids.get(&cell).filter(|&&to| open && to != id) {
+                graph.add_edge(id, to, length);
+            }
+        }
+    }
+    Junctions { graph, coords: nodes, ids }
+}
+
+#[derive(Debug, Clone)]
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new(size: usize) -> Self {
+        Self { words: vec![0; size.div_ceil(64)] }
+    }
+    fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+    fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+    fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+    fn copy_from(&mut self, other: &Self) {
+        self.words.copy_from_slice(&other.words);
+    }
+}
+
+/// Partial path of the longest path search.
+#[derive(Debug, Clone)]
+struct Branch {
+    node: usize,
+    length: u64,
+    /// Upper bound for the length still to come, the heaviest edge into every unvisited node.
+    remaining: u64,
+    visited: BitSet,
+    /// Scratch space of [`LongestPath::reachable_bound`], allocated once per search and branch.
+    seen: BitSet,
+    stack: Vec<usize>,
+}
+
+struct LongestPath<'a> {
+    graph: &'a DiGraph,
+    start: usize,
+    goal: usize,
+    /// Length of the edges forced between `goal` and the actual end.
+    forced_length: u64,
+    forced: Vec<usize>,
+    heaviest_in: Vec<u64>,
+}
+
+impl<'a> LongestPath<'a> {
+    const PARALLEL_BRANCHES: usize = 64;
+
+    fn new(graph: &'a DiGraph, start: usize, end: usize) -> Self {
+        let mut heaviest_in = vec![0; graph.node_count()];
+        let mut predecessors = vec![vec![]; graph.node_count()];
+        for (from, edges) in graph.adjacency.iter().enumerate() {
+            for &(to, weight) in edges {
+                heaviest_in[to] = heaviest_in[to].max(weight);
+                predecessors[to].push((from, weight));
+            }
+        }
+        // A goal entered from a single node can only be reached through it, so the path may stop there
+        let (mut goal, mut forced_length, mut forced) = (end, 0, vec![]);
+        while goal != start {
+            let entries = predecessors[goal].iter()
+                .filter(|(from, _)| *from != goal && !forced.contains(from))
+                .collect::<Vec<_>>();
+            match entries[..] {
+                [&(from, _), ..] if entries.iter().all(|(other, _)| *other == from) => {
+                    forced_length += entries.iter().map(|(_, weight)| *weight).max().expect("Infallible");
+                    forced.push(goal);
+                    goal = from;
+                }
+                _ => break,
+            }
+        }
+        Self { graph, start, goal, forced_length, forced, heaviest_in }
+    }
+
+    fn run(&self, parallel: bool) -> Option<u64> {
+        let mut visited = BitSet::new(self.graph.node_count());
+        visited.insert(self.start);
+        self.forced.iter().for_each(|&node| visited.insert(node));
+        let remaining = (0..self.graph.node_count())
+            .filter(|&node| !visited.contains(node))
+            .map(|node| self.heaviest_in[node])
+            .sum();
+        let seen = visited.clone();
+        let root = Branch { node: self.start, length: 0, remaining, visited, seen, stack: vec![] };
+        // Best length found so far plus one, zero until the goal has been reached
+        let best = AtomicU64::new(0);
+        if parallel {
+            let mut branches = vec![root];
+            while branches.len() < Self::PARALLEL_BRANCHES && branches.iter().any(|branch| branch.node != self.goal) {
+                branches = branches.into_iter()
+                    .flat_map(|branch| self.expand(branch))
+                    .collect();
+            }
+            branches.into_par_iter()
+                .for_each(|mut branch| self.dfs(&mut branch, &best));
+        } else {
+            let mut root = root;
+            self.dfs(&mut root, &best);
+        }
+        best.into_inner()
+            .checked_sub(1)
+            .map(|length| length + self.forced_length)
+    }
+
+    fn expand(&self, branch: Branch) -> Vec<Branch> {
+        if branch.node == self.goal {
+            return vec![branch];
+        }
+        self.graph.neighbours(branch.node)
+            .filter(|&(next, _)| !branch.visited.contains(next))
+            .map(|(next, weight)| {
+                let mut next_branch = branch.clone();
+                next_branch.visited.insert(next);
+                next_branch.node = next;
+                next_branch.length += weight;
+                next_branch.remaining -= self.heaviest_in[next];
+                next_branch
+            })
+            .collect()
+    }
+
+    /// Heaviest edge into every unvisited node reachable from the branch, `None` without the goal among them.
+    fn reachable_bound(&self, branch: &mut Branch) -> Option<u64> {
+        let Branch { node, visited, seen, stack, .. } = branch;
+        seen.copy_from(visited);
+        stack.push(*node);
+        let mut bound = 0;
+        while let Some(node) = stack.pop() {
+            for &(next, _) in &self.graph.adjacency[node] {
+                if !seen.contains(next) {
+                    seen.insert(next);
+                    bound += self.heaviest_in[next];
+                    stack.push(next);
+                }
+            }
+        }
+        seen.contains(self.goal).then_some(bound)
+    }
+
+    fn dfs(&self, branch: &mut Branch, best: &AtomicU64) {
+        if branch.node == self.goal {
+            best.fetch_max(branch.length + 1, Ordering::Relaxed);
+            return;
+        }
+        let best_length = best.load(Ordering::Relaxed);
+        if branch.length + branch.remaining < best_length {
+            return;
+        }
+        // Tighter bound from the nodes still reachable, which also gives up on a cut off goal
+        match self.reachable_bound(branch) {
+            Some(bound) if branch.length + bound >= best_length => {}
+            _ => return,
+        }
+        let Branch { node, length, remaining, .. } = *branch;
+        for &(next, weight) in &self.graph.adjacency[node] {
+            if !branch.visited.contains(next) {
+                branch.visited.insert(next);
+                (branch.node, branch.length, branch.remaining) = (next, length + weight, remaining - self.heaviest_in[next]);
+                self.dfs(branch, best);
+                branch.visited.remove(next);
+            }
+        }
+        (branch.node, branch.length, branch.remaining) = (node, length, remaining);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_from_edges(node_count: usize, edges: &[(usize, usize, u64)]) -> Graph {
+        let mut graph = Graph::new(node_count);
+        edges.iter().for_each(|&(a, b, weight)| graph.add_edge(a, b, weight));
+        graph
+    }
+
+    #[test]
+    fn min_cut_of_weighted_graph() {
+        // Example graph from the Stoer–Wagner paper
+        let graph = graph_from_edges(8, &[
+            (0, 1, 2), (0, 4, 3), (1, 2, 3), (1, 4, 2), (1, 5, 2), (2, 3, 4), (2, 6, 2),
+            (3, 6, 2), (3, 7, 2), (4, 5, 3), (5, 6, 1), (6, 7, 3),
+        ]);
+        let cut = graph.min_cut().expect("Has nodes");
+        assert_eq!(cut.weight, 4);
+        let mut partition = cut.partition;
+        partition.sort();
+        assert!(partition == [2, 3, 6, 7] || partition == [0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn min_cut_of_disconnected_graph() {
+        let graph = graph_from_edges(5, &[(0, 1, 1), (1, 2, 1), (3, 4, 1)]);
+        let cut = graph.min_cut().expect("Has nodes");
+        assert_eq!(cut.weight, 0);
+        assert!(cut.partition.len() == 2 || cut.partition.len() == 3);
+    }
+
+    #[test]
+    fn min_cut_needs_two_nodes() {
+        assert_eq!(Graph::new(1).min_cut(), None);
+    }
+
+    fn ladder(length: usize) -> DiGraph {
+        // Nodes 2 * i and 2 * i + 1 form rung i
+        let mut graph = DiGraph::new(2 * length);
+        for i in 0..length {
+            graph.add_undirected_edge(2 * i, 2 * i + 1, 1);
+            if i + 1 < length {
+                graph.add_undirected_edge(2 * i, 2 * i + 2, 1);
+                graph.add_undirected_edge(2 * i + 1, 2 * i + 3, 1);
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn longest_path_beyond_64_nodes() {
+        let graph = ladder(50);
+        assert_eq!(graph.node_count(), 100);
+        // Snaking through every rung visits all nodes
+        assert_eq!(graph.longest_path(0, 98), Some(99));
+        assert_eq!(graph.longest_path_par(0, 98), Some(99));
+        // Same colour in the bipartite ladder, so one node has to be skipped
+        assert_eq!(graph.longest_path(0, 99), Some(98));
+        assert_eq!(graph.longest_path_par(0, 99), Some(98));
+    }
+
+    #[test]
+    fn longest_path_respects_direction_and_weights() {
+        let mut graph = DiGraph::new(6);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(0, 2, 5);
+        graph.add_edge(1, 2, 1);
+        graph.add_edge(2, 1, 1);
+        graph.add_edge(1, 3, 10);
+        graph.add_edge(2, 3, 1);
+        // Node 4 hangs off the end through a single edge, node 5 is unreachable
+        graph.add_undirected_edge(3, 4, 7);
+        assert_eq!(graph.longest_path(0, 3), Some(16));
+        assert_eq!(graph.longest_path(0, 4), Some(23));
+        assert_eq!(graph.longest_path_par(0, 4), Some(23));
+        assert_eq!(graph.longest_path(3, 0), None);
+        assert_eq!(graph.longest_path(0, 5), None);
+        assert_eq!(graph.longest_path(2, 2), Some(0));
+    }
+
+    #[test]
+    fn contracts_corridors_between_junctions() {
+        let grid = Grid::from_input([
+            "#.#####",
+            "#...>.#",
+            "#.###.#",
+            "#.....#",
+            "#####.#",
+        ].into_iter(), crate::some).expect("Valid grid");
+        let passable = |&c: &char| c != '#';
+        let (start, end) = (Coord::new(1, 0), Coord::new(5, 4));
+        let junctions = contract_corridors(&grid, [], passable, |&c| Direction::from_arrow(c));
+        assert_eq!(junctions.coords(), [start, Coord::new(1, 1), Coord::new(5, 3), end]);
+        let (start, fork, join, end) = (0, 1, 2, 3);
+        assert_eq!(junctions.id(&Coord::new(5, 3)), Some(join));
+        assert_eq!(junctions.id(&Coord::new(3, 1)), None);
+        assert_eq!(junctions.graph.neighbours(fork).collect::<Vec<_>>(), [(start, 1), (join, 6), (join, 6)]);
+        assert_eq!(junctions.graph.neighbours(join).collect::<Vec<_>>(), [(end, 1), (fork, 6)]);
+        assert_eq!(junctions.graph.edge_count(), 7);
+        assert_eq!(junctions.graph.longest_path(start, end), Some(8));
+        let dry = contract_corridors(&grid, [Coord::new(3, 3)], passable, |_| None);
+        assert_eq!(dry.graph.node_count(), 5);
+        assert_eq!(dry.coord(3), Coord::new(5, 3));
+        assert_eq!(dry.graph.edge_count(), 10);
+    }
+
+    #[test]
+    fn rings_without_junctions_are_left_out() {
+        let grid = Grid::from_input(["#####", "#...#", "#.#.#", "#...#", "#####"].into_iter(), crate::some)
+            .expect("Valid grid");
+        let (start, end) = (Coord::new(1, 0), Coord::new(3, 4));
+        let junctions = contract_corridors(&grid, [start, end], |&c: &char| c != '#', |_| None);
+        assert_eq!(junctions.coords(), [start, end]);
+        assert_eq!(junctions.graph.edge_count(), 0);
+        assert_eq!(junctions.graph.longest_path(0, 1), None);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use crate::{Coord, Error, Grid, parse_token};
+
+/// Position in a [`Grid3`], `z` growing upwards through the layers.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Coord3 {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Coord3 {
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { x, y, z }
+    }
+    pub fn minimum(&self, other: &Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+    pub fn maximum(&self, other: &Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+    /// Position within the layer.
+    pub fn xy(&self) -> Coord {
+        Coord::new(self.x, self.y)
+    }
+    /// Minimum and maximum corner of the box containing all `points`, `None` without points.
+    pub fn bounding_box<I>(points: I) -> Option<(Self, Self)> where I: IntoIterator<Item=Self> {
+        points.into_iter()
+            .fold(None, |bounds, point| match bounds {
+                None => Some((point, point)),
+                Some((min, max)) => Some((point.minimum(&min), point.maximum(&max))),
+            })
+    }
+    /// The up to 6 face-adjacent positions that do not go below zero.
+    pub fn neighbours6(&self) -> impl Iterator<Item=Coord3> {
+        let Coord3 { x, y, z } = *self;
+        [
+            x.checked_sub(1).map(|x| Self::new(x, y, z)),
+            Some(Self::new(x + 1, y, z)),
+            y.checked_sub(1).map(|y| Self::new(x, y, z)),
+            Some(Self::new(x, y + 1, z)),
+            z.checked_sub(1).map(|z| Self::new(x, y, z)),
+            Some(Self::new(x, y, z + 1)),
+        ].into_iter().flatten()
+    }
+}
+
+/// Orders layer by layer, then row by row.
+impl Ord for Coord3 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.z.cmp(&other.z)
+            .then(self.y.cmp(&other.y))
+            .then(self.x.cmp(&other.x))
+    }
+}
+
+impl PartialOrd for Coord3 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Coord3 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s.split(',')
+            .map(|coord| parse_token(s, coord.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match coords[..] {
+            [x, y, z] => Ok(Coord3::new(x, y, z)),
+            _ => Err(Error::new("Expected 3 coordinates")),
+        }
+    }
+}
+
+/// Stack of equally sized [`Grid`] layers.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid3<T> {
+    layers: Vec<Grid<T>>,
+    width: usize,
+    depth: usize,
+}
+
+impl<T> Grid3<T> {
+    pub fn new(width: usize, depth: usize, height: usize) -> Grid3<T> where T: Default + Clone {
+        Grid3::new_with_default(T::default(), width, depth, height)
+    }
+    pub fn new_with_default(default: T, width: usize, depth: usize, height: usize) -> Grid3<T> where T: Clone {
+        Grid3 {
+            layers: vec![Grid::new_with_default(default, width, depth); height],
+            width,
+            depth,
+        }
+    }
+    /// Grid spanning from the origin up to and including every point, empty without points.
+    pub fn covering<I>(points: I) -> Grid3<T> where T: Default + Clone, I: IntoIterator<Item=Coord3> {
+        match Coord3::bounding_box(points) {
+            Some((_, max)) => Grid3::new(max.x + 1, max.y + 1, max.z + 1),
+            None => Grid3::new(0, 0, 0),
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    pub fn height(&self) -> usize {
+        self.layers.len()
+    }
+    pub fn layer(&self, z: usize) -> &Grid<T> {
+        &self.layers[z]
+    }
+    pub fn layer_mut(&mut self, z: usize) -> &mut Grid<T> {
+        &mut self.layers[z]
+    }
+    /// Layers from the bottom up.
+    pub fn layers(&self) -> impl Iterator<Item=&Grid<T>> + '_ {
+        self.layers.iter()
+    }
+    pub fn get(&self, coord: &Coord3) -> Option<&T> {
+        self.layers.get(coord.z)?.get_coord(&coord.xy())
+    }
+    pub fn get_mut(&mut self, coord: &Coord3) -> Option<&mut T> {
+        self.layers.get_mut(coord.z)?.get_mut_coord(&coord.xy())
+    }
+    /// Face-adjacent neighbours of `coord` that lie inside the grid, with their values.
+    pub fn neighbours6(&self, coord: Coord3) -> impl Iterator<Item=(Coord3, &T)> + '_ {
+        coord.neighbours6()
+            .filter_map(|neighbour| self.get(&neighbour).map(|value| (neighbour, value)))
+    }
+    pub fn map<U, F>(&self, mut f: F) -> Grid3<U> where F: FnMut(&T) -> U {
+        Grid3 {
+            layers: self.layers.iter().map(|layer| layer.map(&mut f)).collect(),
+            width: self.width,
+            depth: self.depth,
+        }
+    }
+}
+
+impl<T> Index<Coord3> for Grid3<T> {
+    type Output = T;
+    fn index(&self, index: Coord3) -> &Self::Output {
+        self.get(&index).expect("Grid index out of bounds")
+    }
+}
+
+impl<T> IndexMut<Coord3> for Grid3<T> {
+    fn index_mut(&mut self, index: Coord3) -> &mut Self::Output {
+        self.get_mut(&index).expect("Grid index out of bounds")
+    }
+}
+
+/// Prints the layers from the bottom up, each followed by a dashed separator.
+impl<T> Display for Grid3<T> where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for layer in &self.layers {
+            let layer = layer.to_string();
+            let width = layer.lines().next().map_or(0, |line| line.chars().count());
+            writeln!(f, "{layer}{:-<width$}", "")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use super::*;
+
+    #[test]
+    fn coord3_parses_and_orders() {
+        assert_eq!("1,2,3".parse::<Coord3>(), Ok(Coord3::new(1, 2, 3)));
+        assert!("1,2".parse::<Coord3>().is_err());
+        assert!("1,x,3".parse::<Coord3>().is_err());
+        assert!(Coord3::new(5, 5, 1) < Coord3::new(0, 0, 2));
+        assert!(Coord3::new(5, 0, 1) < Coord3::new(0, 1, 1));
+    }
+
+    #[test]
+    fn bounding_box_of_points() {
+        let points = [Coord3::new(1, 5, 2), Coord3::new(3, 0, 4), Coord3::new(2, 2, 1)];
+        assert_eq!(Coord3::bounding_box(points), Some((Coord3::new(1, 0, 1), Coord3::new(3, 5, 4))));
+        assert_eq!(Coord3::bounding_box([]), None);
+        let grid = Grid3::<u8>::covering(points);
+        assert_eq!((grid.width(), grid.depth(), grid.height()), (4, 6, 5));
+    }
+
+    #[test]
+    fn neighbours6_stay_in_grid() {
+        let grid = Grid3::<u8>::new(2, 2, 3);
+        assert_eq!(Coord3::new(1, 1, 1).neighbours6().count(), 6);
+        assert_eq!(grid.neighbours6(Coord3::new(0, 0, 0)).map(|(coord, _)| coord).collect_vec(),
+                   [Coord3::new(1, 0, 0), Coord3::new(0, 1, 0), Coord3::new(0, 0, 1)]);
+        assert_eq!(grid.neighbours6(Coord3::new(1, 1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn layers_display_bottom_up() {
+        let mut grid = Grid3::new_with_default('.', 3, 1, 2);
+        grid[Coord3::new(1, 0, 1)] = '#';
+        assert_eq!(grid.layer(1)[Coord::new(1, 0)], '#');
+        assert_eq!(grid.to_string(), "...\n---\n.#.\n---\n");
+        assert_eq!(grid.map(|&c| c == '#').layers().filter(|layer| layer.position(|&cell| cell).is_some()).count(), 1);
+    }
+}
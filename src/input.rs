@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{Read, stdin};
+use std::path::{Path, PathBuf};
+use crate::Error;
+
+pub const DEFAULT_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn day_file<P>(dir: P, day: u8) -> Self where P: AsRef<Path> {
+        Source::File(dir.as_ref().join(format!("{day:02}")))
+    }
+
+    /// An explicit path wins and `-` forces stdin, otherwise `dir/NN` is used when it exists.
+    pub fn resolve<P>(arg: Option<&str>, dir: P, day: u8) -> Self where P: AsRef<Path> {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None => match Self::day_file(dir, day) {
+                Source::File(path) if path.is_file() => Source::File(path),
+                _ => Source::Stdin,
+            },
+        }
+    }
+
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Splits `input` into sections separated by blank lines, each a subslice of `input` without
+/// its trailing line break.
+pub fn sections(input: &str) -> impl Iterator<Item=&str> {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let (mut start, mut end) = (None, 0);
+        for line in input[offset..].split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + line.trim_end_matches(['\n', '\r']).len();
+            }
+            offset += line.len();
+        }
+        start.map(|start| &input[start..end])
+    })
+}
+
+/// Parses every blank-line separated section of `input`, naming the failing section and
+/// locating its errors within the whole input.
+pub fn parse_sections<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(&str) -> Result<T, Error>,
+{
+    sections(input)
+        .enumerate()
+        .map(|(i, section)| {
+            parse(section).map_err(|err| {
+                Error { reason: format!("Section {}: {}", i + 1, err.reason), ..err }.within(input, section)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_lines, parse_token};
+    use super::*;
+
+    #[test]
+    fn resolves_input_sources() {
+        let dir = std::env::temp_dir().join(format!("aoc23-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("Cannot create input dir");
+        fs::write(dir.join("07"), "input").expect("Cannot write input");
+        assert_eq!(Source::resolve(Some("-"), &dir, 7), Source::Stdin);
+        assert_eq!(Source::resolve(Some("day7.txt"), &dir, 7), Source::File("day7.txt".into()));
+        assert_eq!(Source::resolve(None, &dir, 7), Source::File(dir.join("07")));
+        assert_eq!(Source::resolve(None, &dir, 8), Source::Stdin);
+        assert_eq!(Source::resolve(None, &dir, 7).read().expect("Readable"), "input");
+        fs::remove_dir_all(&dir).expect("Cannot remove input dir");
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = "a\nb\n\n\nc\r\n  \nd\n\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+        assert_eq!(sections("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn parse_sections_reports_section() {
+        let err = parse_sections("1\n2\n\n3\nx", |section| parse_lines(section, |line| parse_token::<u32>(line, line)))
+            .expect_err("Not a number");
+        assert_eq!(err.to_string(), "line 5, column 1: Section 2: Bad value: \"x\"");
+    }
+}
@@ -1,18 +1,31 @@
 use std::fmt::{Debug, Display, Formatter};
-use std::io::stdin;
-use std::ops::{Index, IndexMut, Sub};
+use std::ops::{Add, Index, IndexMut, Sub};
 use std::str::FromStr;
 use itertools::Itertools;
 use crate::Direction::{East, North, South, West};
+
+pub mod bench;
+pub mod check;
+pub mod cycle;
+pub mod days;
+mod error;
+pub mod graph;
+mod grid3;
+pub mod input;
+pub mod report;
+pub mod runner;
+pub mod search;
+mod solution;
+
+pub use error::{Error, parse_lines, parse_token};
+pub use grid3::{Coord3, Grid3};
+pub use input::{parse_sections, sections};
+pub use solution::{Day, Solution, solve};
+
 #[macro_export]
 macro_rules! run {
-    ($x:tt) => {
-        {
-            let start = std::time::Instant::now();
-            let (r1, r2) = $x;
-            let time = start.elapsed().as_secs_f64() * 1000.0;
-            println!("Part 1: {r1}\nPart 2: {r2}\nTime: {time}ms");
-        }
+    ($solution:ty) => {
+        $crate::runner::run::<$solution>()
     };
 }
 
@@ -32,6 +45,72 @@ impl Direction {
             East | West => (North, South),
         }
     }
+    pub fn turn_left(&self) -> Self {
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+    pub fn turn_right(&self) -> Self {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+    pub fn opposite(&self) -> Self {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+    /// Direction of a beam travelling in `self` after bouncing off `mirror`.
+    pub fn reflect(&self, mirror: Mirror) -> Self {
+        match (mirror, self) {
+            (Mirror::Slash, North | South) | (Mirror::Backslash, East | West) => self.turn_right(),
+            (Mirror::Slash, East | West) | (Mirror::Backslash, North | South) => self.turn_left(),
+        }
+    }
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(North),
+            '>' => Some(East),
+            'v' => Some(South),
+            '<' => Some(West),
+            _ => None,
+        }
+    }
+    pub fn arrow(&self) -> char {
+        match self {
+            North => '^',
+            East => '>',
+            South => 'v',
+            West => '<',
+        }
+    }
+}
+
+/// Diagonal mirror as drawn in the input, `/` or `\`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Mirror {
+    Slash,
+    Backslash,
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = char;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '/' => Ok(Mirror::Slash),
+            '\\' => Ok(Mirror::Backslash),
+            _ => Err(value),
+        }
+    }
 }
 
 impl TryFrom<char> for Direction {
@@ -48,16 +127,65 @@ impl TryFrom<char> for Direction {
 }
 
 impl FromStr for Direction {
-    type Err = ();
+    type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad_direction = || Error::new(format!("Bad direction: {s:?}"));
         if s.len() != 1 {
-            Err(())
+            Err(bad_direction())
         } else {
             s.chars()
                 .next()
                 .expect("Infallible")
                 .try_into()
-                .map_err(|_| ())
+                .map_err(|_| bad_direction())
+        }
+    }
+}
+
+/// Compass directions including the diagonals, for 8-cell neighbourhoods.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Self::North, Self::NorthEast, Self::East, Self::SouthEast,
+        Self::South, Self::SouthWest, Self::West, Self::NorthWest,
+    ];
+    /// Unit step as `(x, y)` with `y` growing southwards.
+    pub fn offset(&self) -> (i64, i64) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+    pub fn is_diagonal(&self) -> bool {
+        let (x, y) = self.offset();
+        x != 0 && y != 0
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            North => Self::North,
+            East => Self::East,
+            South => Self::South,
+            West => Self::West,
         }
     }
 }
@@ -80,6 +208,17 @@ impl Coord {
             West => if self.x > 0 { Some(Self::new(self.x - 1, self.y)) } else { None }
         }
     }
+    pub fn next8(&self, direction: Direction8) -> Option<Self> {
+        let (x_off, y_off) = direction.offset();
+        Some(Self::new(self.x.checked_add_signed(x_off as isize)?, self.y.checked_add_signed(y_off as isize)?))
+    }
+    /// The up to 8 surrounding cells that lie inside `grid`.
+    pub fn neighbours8<T>(&self, grid: &Grid<T>) -> impl Iterator<Item=Coord> {
+        let (coord, width, height) = (*self, grid.width(), grid.height());
+        Direction8::ALL.into_iter()
+            .filter_map(move |direction| coord.next8(direction))
+            .filter(move |neighbour| neighbour.x < width && neighbour.y < height)
+    }
     pub fn next_xy(x: usize, y: usize, direction: Direction) -> Option<Self> {
         Coord::new(x, y).next(direction)
     }
@@ -107,6 +246,9 @@ impl Coord {
             }
         }
     }
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        *self - *other
+    }
     pub fn sub_elements(&self, other: &Self) -> (isize, isize) {
         (self.x as isize - other.x as isize, self.y as isize - other.y as isize)
     }
@@ -124,12 +266,13 @@ impl Coord {
 
 #[macro_export]
 macro_rules! xy {
-    ($x:expr, $y:expr) => { ::aoc23::Coord::new($x, $y) };
+    ($x:expr, $y:expr) => { $crate::Coord::new($x, $y) };
 }
 
 impl Sub for Coord {
     type Output = usize;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self::Output {
         self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
     }
@@ -141,53 +284,169 @@ impl From<(usize, usize)> for Coord {
     }
 }
 
-#[derive(Clone, Eq, PartialEq)]
-pub struct Grid<T> where T: Copy {
+/// Signed counterpart of [`Coord`] for unbounded planes, where moving never leaves the valid range.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ICoord {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl ICoord {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+    pub fn next(&self, direction: Direction) -> Self {
+        self.next_n(direction, 1)
+    }
+    pub fn next_n(&self, direction: Direction, distance: i64) -> Self {
+        match direction {
+            North => Self::new(self.x, self.y - distance),
+            East => Self::new(self.x + distance, self.y),
+            South => Self::new(self.x, self.y + distance),
+            West => Self::new(self.x - distance, self.y),
+        }
+    }
+    pub fn next8(&self, direction: Direction8) -> Self {
+        let (x_off, y_off) = direction.offset();
+        Self::new(self.x + x_off, self.y + y_off)
+    }
+    pub fn neighbours8(&self) -> [ICoord; 8] {
+        Direction8::ALL.map(|direction| self.next8(direction))
+    }
+    pub fn direction_to(&self, other: &Self) -> Option<Direction> {
+        match (other.x - self.x, other.y - self.y) {
+            (0, 0) => None,
+            (0, y_off) => Some(if y_off > 0 { South } else { North }),
+            (x_off, 0) => Some(if x_off > 0 { East } else { West }),
+            _ => None,
+        }
+    }
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+    pub fn orthogonal_neighbours(&self) -> [ICoord; 4] {
+        Direction::ALL.map(|direction| self.next(direction))
+    }
+}
+
+impl Add for ICoord {
+    type Output = ICoord;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for ICoord {
+    type Output = ICoord;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl From<(i64, i64)> for ICoord {
+    fn from(value: (i64, i64)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Coord> for ICoord {
+    fn from(value: Coord) -> Self {
+        Self::new(value.x as i64, value.y as i64)
+    }
+}
+
+/// Unit vector pointing in the direction.
+impl From<Direction> for ICoord {
+    fn from(value: Direction) -> Self {
+        ICoord::new(0, 0).next(value)
+    }
+}
+
+impl TryFrom<ICoord> for Direction {
+    type Error = ICoord;
+
+    fn try_from(value: ICoord) -> Result<Self, Self::Error> {
+        match (value.x, value.y) {
+            (0, -1) => Ok(North),
+            (1, 0) => Ok(East),
+            (0, 1) => Ok(South),
+            (-1, 0) => Ok(West),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<ICoord> for Coord {
+    type Error = ICoord;
+
+    fn try_from(value: ICoord) -> Result<Self, Self::Error> {
+        match (usize::try_from(value.x), usize::try_from(value.y)) {
+            (Ok(x), Ok(y)) => Ok(Coord::new(x, y)),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
     grid: Vec<T>,
     width: usize,
     height: usize,
 }
 
-impl<T> Grid<T> where T: Copy {
-    pub fn new(width: usize, height: usize) -> Grid<T> where T: Default {
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize) -> Grid<T> where T: Default + Clone {
         Grid::new_with_default(T::default(), width, height)
     }
-    pub fn new_with_default(default: T, width: usize, height: usize) -> Grid<T> {
+    pub fn new_with_default(default: T, width: usize, height: usize) -> Grid<T> where T: Clone {
         Grid{
             grid: vec![default; width * height],
             width,
             height,
         }
     }
-    pub fn from_input<I, F>(lines: I, conv_fn: F) -> Option<Self>
+    pub fn from_input<I, S, F>(lines: I, conv_fn: F) -> Result<Self, Error>
         where
-            I: Iterator<Item=String>,
+            I: Iterator<Item=S>,
+            S: AsRef<str>,
             F: Fn(char) -> Option<T> + Copy,
     {
-        let rows = lines.map(|line| {
-            let row = line.chars()
-                .map(conv_fn)
-                .collect::<Option<Vec<T>>>()?;
-            let width = row.len();
-            Some((row, width))
-        })
-            .collect::<Option<Vec<(Vec<T>, usize)>>>()?;
-        let height = rows.len();
-        if height == 0 {
-            None
-        } else {
-            let width = rows.iter().map(|(_, width)| *width).all_equal_value().ok()?;
-            if width == 0 {
-                None
-            } else {
-                let grid = rows.into_iter()
-                    .map(|(row, _)| row.into_iter())
-                    .flatten()
-                    .collect_vec();
-                Some(Grid { grid, width, height })
+        let mut grid = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in lines.enumerate() {
+            let row_start = grid.len();
+            for (x, c) in line.as_ref().chars().enumerate() {
+                let cell = conv_fn(c).ok_or_else(|| Error {
+                    line: Some(y + 1),
+                    column: Some(x + 1),
+                    reason: format!("Unexpected character {c:?}"),
+                })?;
+                grid.push(cell);
+            }
+            let row_width = grid.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => return Err(Error {
+                    line: Some(y + 1),
+                    column: None,
+                    reason: format!("Row has width {row_width}, expected {width}"),
+                }),
+                _ => {}
             }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid { grid, width, height }),
+            _ => Err(Error::new("Empty grid")),
         }
     }
+    /// Parses every blank-line separated section of `input` as a grid.
+    pub fn many_from_input<F>(input: &str, conv_fn: F) -> Result<Vec<Self>, Error> where F: Fn(char) -> Option<T> + Copy {
+        parse_sections(input, |section| Grid::from_input(section.lines(), conv_fn))
+    }
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
         if x < self.width && y < self.height {
             Some(&self.grid[x + y * self.width])
@@ -208,6 +467,18 @@ impl<T> Grid<T> where T: Copy {
     pub fn get_mut_coord(&mut self, coord: &Coord) -> Option<&mut T> {
         self.get_mut(coord.x, coord.y)
     }
+    /// Orthogonal neighbours of `coord` that lie inside the grid, with their values.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item=(Coord, &T)> + '_ {
+        self.neighbours_with_direction(coord)
+            .map(|(_, neighbour, value)| (neighbour, value))
+    }
+    pub fn neighbours_with_direction(&self, coord: Coord) -> impl Iterator<Item=(Direction, Coord, &T)> + '_ {
+        Direction::ALL.into_iter()
+            .filter_map(move |direction| {
+                let neighbour = coord.next(direction)?;
+                self.get_coord(&neighbour).map(|value| (direction, neighbour, value))
+            })
+    }
     pub fn width(&self) -> usize {
         self.width
     }
@@ -225,15 +496,78 @@ impl<T> Grid<T> where T: Copy {
     pub fn row(&self, y: usize) -> &'_ [T] {
         &self.grid[y * self.width..(y + 1) * self.width]
     }
+    /// Cells in row-major order together with their coordinates.
+    pub fn iter_coords(&self) -> impl Iterator<Item=(Coord, &T)> + '_ {
+        self.grid.iter()
+            .enumerate()
+            .map(|(i, value)| (Coord::new(i % self.width, i / self.width), value))
+    }
+    pub fn position<P>(&self, mut predicate: P) -> Option<Coord> where P: FnMut(&T) -> bool {
+        self.iter_coords()
+            .find_map(|(coord, value)| predicate(value).then_some(coord))
+    }
+    pub fn positions<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item=Coord> + 'a where P: FnMut(&T) -> bool + 'a {
+        self.iter_coords()
+            .filter_map(move |(coord, value)| predicate(value).then_some(coord))
+    }
+    pub fn map<U, F>(&self, f: F) -> Grid<U> where F: FnMut(&T) -> U {
+        Grid {
+            grid: self.grid.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> + '_ {
+        assert!(x < self.width, "Grid column out of bounds");
+        self.grid[x..].iter().step_by(self.width)
+    }
+    /// Builds a `width` × `height` grid taking each cell from the position `source` maps it to.
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+        where
+            T: Clone,
+            F: Fn(usize, usize) -> (usize, usize),
+    {
+        let grid = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (x, y) = source(x, y);
+                self.grid[x + y * self.width].clone()
+            })
+            .collect();
+        Grid { grid, width, height }
+    }
+    /// Mirrors the grid over its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+    /// Rotates clockwise, the top row becomes the right column.
+    pub fn rotate_cw(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+    /// Rotates counter-clockwise, the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Grid<T> where T: Clone {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> where T: Clone {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> where T: Clone {
+        self.remap(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid { grid: self }
+    }
 }
 
-impl<T> Display for Grid<T> where T: Copy + Display {
+impl<T> Display for Grid<T> where T: Display {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for line in self.iter() {
             for c in line {
                 write!(f, "{c}")?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
@@ -267,7 +601,7 @@ pub struct GridRowIterator<T> {
     position: usize,
 }
 
-impl<T> Iterator for GridRowIterator<T> where T: Copy {
+impl<T> Iterator for GridRowIterator<T> where T: Clone {
     type Item = Box<[T]>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -281,7 +615,7 @@ impl<T> Iterator for GridRowIterator<T> where T: Copy {
     }
 }
 
-impl<T> IntoIterator for Grid<T> where T: Copy {
+impl<T> IntoIterator for Grid<T> where T: Clone {
     type Item = Box<[T]>;
     type IntoIter = GridRowIterator<T>;
 
@@ -295,23 +629,217 @@ impl<T> IntoIterator for Grid<T> where T: Copy {
     }
 }
 
-impl<'a, T> Index<Coord> for Grid<T> where T: Copy {
+impl<T> Index<Coord> for Grid<T> {
     type Output = T;
     fn index(&self, index: Coord) -> &Self::Output {
         self.get(index.x, index.y).expect("Grid index out of bounds")
     }
 }
 
-impl<'a, T> IndexMut<Coord> for Grid<T> where T: Copy {
+impl<T> IndexMut<Coord> for Grid<T> {
     fn index_mut(&mut self, index: Coord) -> &mut Self::Output {
         self.get_mut(index.x, index.y).expect("Grid index out of bounds")
     }
 }
 
+/// Read-only view repeating a [`Grid`] infinitely in every direction.
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Clone for WrappingGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WrappingGrid<'_, T> {}
+
+impl<T> WrappingGrid<'_, T> {
+    /// Position of `coord` inside the original grid.
+    pub fn wrap(&self, coord: ICoord) -> Coord {
+        Coord::new(
+            coord.x.rem_euclid(self.grid.width as i64) as usize,
+            coord.y.rem_euclid(self.grid.height as i64) as usize,
+        )
+    }
+    /// Which copy of the grid `coord` falls in, the original being at `(0, 0)`.
+    pub fn tile(&self, coord: ICoord) -> ICoord {
+        ICoord::new(coord.x.div_euclid(self.grid.width as i64), coord.y.div_euclid(self.grid.height as i64))
+    }
+    pub fn get(&self, coord: ICoord) -> &T {
+        &self.grid[self.wrap(coord)]
+    }
+}
+
+impl<T> Index<ICoord> for WrappingGrid<'_, T> {
+    type Output = T;
+    fn index(&self, index: ICoord) -> &Self::Output {
+        self.get(index)
+    }
+}
+
 pub fn some<T>(value: T) -> Option<T> {
     Some(value)
 }
 
-pub fn stdin_lines() -> impl Iterator<Item=String> {
-    stdin().lines().map(|x| x.expect("Bad input"))
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icoord_moves_past_zero() {
+        let origin = ICoord::new(0, 0);
+        assert_eq!(origin.next(North), ICoord::new(0, -1));
+        assert_eq!(origin.next_n(West, 3), ICoord::new(-3, 0));
+        assert_eq!(origin.orthogonal_neighbours(),
+                   [ICoord::new(0, -1), ICoord::new(1, 0), ICoord::new(0, 1), ICoord::new(-1, 0)]);
+        assert_eq!(ICoord::new(-2, 3).manhattan_distance(&ICoord::new(1, -1)), 7);
+    }
+
+    #[test]
+    fn icoord_direction_matches_coord() {
+        let (a, b) = (xy!(2, 2), xy!(2, 5));
+        assert_eq!(ICoord::from(a).direction_to(&b.into()), a.direction_to(&b));
+        assert_eq!(ICoord::from(b).direction_to(&a.into()), b.direction_to(&a));
+    }
+
+    #[test]
+    fn wrapping_grid_repeats() {
+        let grid = Grid::from_input(["ab", "cd", "ef"].into_iter(), some).expect("Valid grid");
+        let wrapping = grid.wrapping();
+        assert_eq!(wrapping[ICoord::new(0, 0)], 'a');
+        assert_eq!(wrapping[ICoord::new(-1, 0)], 'b');
+        assert_eq!(wrapping[ICoord::new(3, -1)], 'f');
+        assert_eq!(wrapping[ICoord::new(-4, 7)], 'c');
+        assert_eq!(wrapping.wrap(ICoord::new(-4, 7)), xy!(0, 1));
+        assert_eq!(wrapping.tile(ICoord::new(-4, 7)), ICoord::new(-2, 2));
+        assert_eq!(wrapping.tile(ICoord::new(1, 2)), ICoord::new(0, 0));
+    }
+
+    #[test]
+    fn icoord_converts_to_coord() {
+        assert_eq!(Coord::try_from(ICoord::new(3, 4)), Ok(xy!(3, 4)));
+        assert_eq!(Coord::try_from(ICoord::new(3, -1)), Err(ICoord::new(3, -1)));
+        assert_eq!(ICoord::from(xy!(3, 4)), ICoord::new(3, 4));
+    }
+
+    #[test]
+    fn neighbours8_stay_in_grid() {
+        let grid = Grid::from_input(["abc", "def", "ghi"].into_iter(), some).expect("Valid grid");
+        assert_eq!(xy!(1, 1).neighbours8(&grid).count(), 8);
+        assert_eq!(xy!(0, 0).neighbours8(&grid).collect_vec(), [xy!(1, 0), xy!(1, 1), xy!(0, 1)]);
+        assert_eq!(xy!(2, 1).neighbours8(&grid).map(|coord| grid[coord]).collect::<String>(), "ciheb");
+    }
+
+    #[test]
+    fn direction8_offsets() {
+        assert_eq!(Direction8::ALL.iter().filter(|direction| direction.is_diagonal()).count(), 4);
+        assert_eq!(Direction::ALL.map(|direction| ICoord::new(0, 0).next8(direction.into())),
+                   ICoord::new(0, 0).orthogonal_neighbours());
+        assert_eq!(ICoord::new(0, 0).neighbours8().into_iter().map(|coord| coord.x + coord.y).sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn direction_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.opposite(), direction);
+            let (side, other_side) = direction.orthogonal();
+            assert!([side, other_side].contains(&direction.turn_left()));
+            assert!([side, other_side].contains(&direction.turn_right()));
+        }
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+    }
+
+    #[test]
+    fn direction_reflects() {
+        assert_eq!(Direction::ALL.map(|direction| direction.reflect(Mirror::Slash)), [East, North, West, South]);
+        assert_eq!(Direction::ALL.map(|direction| direction.reflect(Mirror::Backslash)), [West, South, East, North]);
+        for (direction, mirror) in Direction::ALL.into_iter().cartesian_product([Mirror::Slash, Mirror::Backslash]) {
+            assert_eq!(direction.reflect(mirror).reflect(mirror), direction);
+            assert_eq!(direction.opposite().reflect(mirror), direction.reflect(mirror).opposite());
+        }
+        assert_eq!(Mirror::try_from('/'), Ok(Mirror::Slash));
+        assert_eq!(Mirror::try_from('\\'), Ok(Mirror::Backslash));
+        assert_eq!(Mirror::try_from('|'), Err('|'));
+    }
+
+    #[test]
+    fn direction_converts() {
+        assert_eq!(Direction::ALL.map(|direction| direction.arrow()), ['^', '>', 'v', '<']);
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+            assert_eq!(Direction::try_from(ICoord::from(direction)), Ok(direction));
+            assert_eq!(ICoord::from(direction).manhattan_distance(&ICoord::new(0, 0)), 1);
+        }
+        assert_eq!(Direction::from_arrow('.'), None);
+        assert_eq!(ICoord::from(North), ICoord::new(0, -1));
+        assert_eq!(Direction::try_from(ICoord::new(1, 1)), Err(ICoord::new(1, 1)));
+        assert_eq!(Direction::try_from(ICoord::new(0, 2)), Err(ICoord::new(0, 2)));
+    }
+
+    #[test]
+    fn grid_neighbours_stay_in_grid() {
+        let grid = Grid::from_input(["abc", "def"].into_iter(), some).expect("Valid grid");
+        assert_eq!(grid.neighbours(xy!(0, 0)).collect_vec(), [(xy!(1, 0), &'b'), (xy!(0, 1), &'d')]);
+        assert_eq!(grid.neighbours(xy!(1, 1)).map(|(_, &c)| c).collect::<String>(), "bfd");
+        assert_eq!(grid.neighbours_with_direction(xy!(2, 0)).collect_vec(),
+                   [(South, xy!(2, 1), &'f'), (West, xy!(1, 0), &'b')]);
+        assert_eq!(grid.neighbours(xy!(3, 0)).count(), 1);
+    }
+
+    #[test]
+    fn grid_columns_and_rotations() {
+        let grid = Grid::from_input(["abc", "def"].into_iter(), some).expect("Valid grid");
+        let rows = |grid: Grid<char>| grid.iter().map(|row| row.iter().collect::<String>()).collect_vec();
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(rows(grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(rows(grid.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(rows(grid.rotate_ccw()), ["cf", "be", "ad"]);
+        assert_eq!(rows(grid.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(rows(grid.flip_vertical()), ["def", "abc"]);
+        assert!(grid.rotate_cw().rotate_ccw() == grid);
+        assert!(grid.rotate_cw().rotate_cw() == grid.flip_horizontal().flip_vertical());
+        assert!(grid.transpose().transpose() == grid);
+        assert_eq!(grid.transpose().width(), 2);
+    }
+
+    #[test]
+    fn grid_search_and_map() {
+        let grid = Grid::from_input(["a.b", ".a."].into_iter(), some).expect("Valid grid");
+        assert_eq!(grid.iter_coords().nth(4), Some((xy!(1, 1), &'a')));
+        assert_eq!(grid.iter_coords().count(), 6);
+        assert_eq!(grid.position(|&c| c == 'b'), Some(xy!(2, 0)));
+        assert_eq!(grid.position(|&c| c == 'c'), None);
+        assert_eq!(grid.positions(|&c| c == 'a').collect_vec(), [xy!(0, 0), xy!(1, 1)]);
+        let dots = grid.map(|&c| c == '.');
+        assert_eq!((dots.width(), dots.height()), (3, 2));
+        assert_eq!(dots.positions(|&dot| dot).collect_vec(), [xy!(1, 0), xy!(0, 1), xy!(2, 1)]);
+    }
+
+    #[test]
+    fn many_grids_from_input() {
+        let grids = Grid::many_from_input("ab\ncd\n\nef\n", some).expect("Valid grids");
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[1].row(0), ['e', 'f']);
+        let err = Grid::many_from_input("ab\ncd\n\nef\ng", some).expect_err("Ragged grid");
+        assert_eq!(err.to_string(), "line 5: Section 2: Row has width 1, expected 2");
+    }
+
+    #[test]
+    fn grid_holds_non_copy_cells() {
+        let mut grid = Grid::<Vec<String>>::new(2, 2);
+        grid[xy!(1, 0)].push("a".to_string());
+        grid[xy!(1, 0)].push("b".to_string());
+        let rotated = grid.rotate_cw();
+        assert_eq!(rotated[xy!(1, 1)], ["a", "b"]);
+        assert_eq!(grid.map(Vec::len).position(|&len| len == 2), Some(xy!(1, 0)));
+        assert_eq!(grid.wrapping()[ICoord::new(-1, 2)].len(), 2);
+    }
+}
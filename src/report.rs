@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub answer: String,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: [PartReport; 2],
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [part1, part2] = &self.parts;
+        writeln!(f, "Part 1: {}\nPart 2: {}", part1.answer, part2.answer)?;
+        write!(f, "Time: {}ms (parse: {}ms, part 1: {}ms, part 2: {}ms)",
+               millis(self.total_time()), millis(self.parse_time), millis(part1.time), millis(part2.time))
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown output format: {s}")),
+        }
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut escaped, c| {
+            match c {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c),
+            }
+            escaped
+        })
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Format {
+    /// Written once before the first report, so that several days can share one output.
+    pub fn write_header<W>(&self, out: &mut W) -> std::io::Result<()> where W: Write {
+        match self {
+            Format::Csv => writeln!(out, "day,part,answer,parse_ms,solve_ms"),
+            Format::Text | Format::Json => Ok(()),
+        }
+    }
+
+    pub fn write_report<W>(&self, out: &mut W, report: &Report) -> std::io::Result<()> where W: Write {
+        match self {
+            Format::Text => writeln!(out, "{report}"),
+            Format::Json => {
+                report.parts.iter()
+                    .zip(1..)
+                    .try_for_each(|(part, n)| {
+                        writeln!(out, r#"{{"day":{},"part":{n},"answer":"{}","parse_ms":{},"solve_ms":{}}}"#,
+                                 report.day, json_escape(&part.answer), millis(report.parse_time), millis(part.time))
+                    })
+            }
+            Format::Csv => {
+                report.parts.iter()
+                    .zip(1..)
+                    .try_for_each(|(part, n)| {
+                        writeln!(out, "{},{n},{},{},{}",
+                                 report.day, csv_escape(&part.answer), millis(report.parse_time), millis(part.time))
+                    })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_escape("plain, text"), "plain, text");
+        assert_eq!(json_escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(json_escape("a\\b"), r"a\\b");
+        assert_eq!(json_escape("two\nlines\t"), r"two\nlines\u0009");
+    }
+
+    #[test]
+    fn escapes_csv_fields() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape(r"back\slash"), r"back\slash");
+        assert_eq!(csv_escape("1,2"), r#""1,2""#);
+        assert_eq!(csv_escape(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_escape("two\nlines"), "\"two\nlines\"");
+    }
+}
@@ -0,0 +1,230 @@
+use std::io::stdout;
+use std::path::PathBuf;
+use std::process;
+use crate::{bench, check, Day, input, Solution};
+use crate::input::Source;
+use crate::report::Format;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub format: Format,
+    pub bench: bool,
+    pub warmup: usize,
+    pub runs: usize,
+    pub bench_output: PathBuf,
+    pub check: bool,
+    pub answers: PathBuf,
+    pub inputs: PathBuf,
+    pub positional: Vec<String>,
+    /// Options given on the command line, by their long name.
+    pub given: Vec<&'static str>,
+}
+
+/// Options that apply when solving.
+pub const RUN_OPTIONS: &[&str] = &["--format", "--check", "--answers", "--inputs"];
+/// Options that apply when benchmarking, besides `--bench` which selects it in the day binaries.
+pub const BENCH_OPTIONS: &[&str] = &["--warmup", "--runs", "--bench-output", "--inputs"];
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            format: Format::default(),
+            bench: false,
+            warmup: 3,
+            runs: 20,
+            bench_output: bench::DEFAULT_OUTPUT.into(),
+            check: false,
+            answers: check::DEFAULT_DIR.into(),
+            inputs: input::DEFAULT_DIR.into(),
+            positional: Vec::new(),
+            given: Vec::new(),
+        }
+    }
+}
+
+fn option_value<I, T>(args: &mut I, name: &str) -> Result<T, String>
+    where
+        I: Iterator<Item=String>,
+        T: std::str::FromStr,
+{
+    args.next()
+        .ok_or(format!("Missing value for {name}"))?
+        .parse()
+        .map_err(|_| format!("Invalid value for {name}"))
+}
+
+impl Options {
+    pub fn parse<I>(args: I) -> Result<Self, String> where I: IntoIterator<Item=String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let name = match arg.as_str() {
+                "--format" | "-f" => {
+                    options.format = args.next()
+                        .ok_or("Missing value for --format")?
+                        .parse()?;
+                    "--format"
+                }
+                "--bench" => {
+                    options.bench = true;
+                    "--bench"
+                }
+                "--warmup" => {
+                    options.warmup = option_value(&mut args, "--warmup")?;
+                    "--warmup"
+                }
+                "--runs" => {
+                    options.runs = option_value(&mut args, "--runs")?;
+                    "--runs"
+                }
+                "--bench-output" => {
+                    options.bench_output = option_value(&mut args, "--bench-output")?;
+                    "--bench-output"
+                }
+                "--check" => {
+                    options.check = true;
+                    "--check"
+                }
+                "--answers" => {
+                    options.answers = option_value(&mut args, "--answers")?;
+                    "--answers"
+                }
+                "--inputs" => {
+                    options.inputs = option_value(&mut args, "--inputs")?;
+                    "--inputs"
+                }
+                _ if arg.starts_with('-') && arg != "-" => return Err(format!("Unknown option: {arg}")),
+                _ => {
+                    options.positional.push(arg);
+                    continue;
+                }
+            };
+            options.given.push(name);
+        }
+        if options.runs == 0 {
+            Err("--runs must be at least 1".to_string())
+        } else {
+            Ok(options)
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1)).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        })
+    }
+
+    /// Fails on the first given option that is not in `allowed`, naming the `command` it does not apply to.
+    pub fn only(&self, allowed: &[&str], command: &str) -> Result<(), String> {
+        match self.given.iter().find(|name| !allowed.contains(name)) {
+            Some(name) => Err(format!("{name} does not apply to {command}")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn input_source(&self, day: u8, arg: Option<&str>) -> Source {
+        Source::resolve(arg, &self.inputs, day)
+    }
+}
+
+pub fn read_input(source: &Source) -> String {
+    source.read().unwrap_or_else(|err| {
+        eprintln!("Cannot read {source}: {err}");
+        process::exit(1);
+    })
+}
+
+/// Benchmarks all `(day, input)` pairs, printing each result and writing them to the bench output file.
+pub fn run_bench<'a, I>(options: &Options, days: I) where I: IntoIterator<Item=(&'a Day, String)> {
+    let reports = days.into_iter()
+        .filter_map(|(day, input)| {
+            bench::bench(day, &input, options.warmup, options.runs)
+                .unwrap_or_else(|err| {
+                    eprintln!("Day {:02}: bad input: {err}", day.day);
+                    None
+                })
+        })
+        .inspect(|report| print!("{report}"))
+        .collect::<Vec<_>>();
+    if let Err(err) = bench::write_output(&options.bench_output, &reports) {
+        eprintln!("Cannot write {}: {err}", options.bench_output.display());
+        process::exit(1);
+    }
+}
+
+pub fn run<S>() where S: Solution {
+    let options = Options::from_env();
+    let applies = if options.bench {
+        options.only(&[BENCH_OPTIONS, &["--bench"]].concat(), "--bench")
+    } else {
+        options.only(RUN_OPTIONS, "solving without --bench")
+    };
+    if let Err(err) = applies {
+        eprintln!("{err}");
+        process::exit(2);
+    }
+    if options.positional.len() > 1 {
+        eprintln!("Usage: p{:02} [INPUT|-] [--format text|json|csv] [--check] [--answers DIR] [--inputs DIR]\n       \
+                   p{:02} [INPUT|-] --bench [--warmup N] [--runs N] [--bench-output PATH] [--inputs DIR]", S::DAY, S::DAY);
+        process::exit(1);
+    }
+    let input = read_input(&options.input_source(S::DAY, options.positional.first().map(String::as_str)));
+    if options.bench {
+        run_bench(&options, [(&Day::new::<S>(), input)]);
+    } else {
+        let report = crate::solve::<S>(&input).unwrap_or_else(|err| {
+            eprintln!("Bad input: {err}");
+            process::exit(1);
+        });
+        let mut out = stdout().lock();
+        options.format.write_header(&mut out)
+            .and_then(|_| options.format.write_report(&mut out, &report))
+            .expect("Cannot write report");
+        if options.check && !check::check_report(&options.answers, &report) {
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_flags_and_positionals() {
+        let options = parse(&["run", "7", "-", "-f", "csv", "--check", "--answers", "out", "--runs", "5"])
+            .expect("Valid options");
+        assert_eq!(options.positional, ["run", "7", "-"]);
+        assert_eq!(options.format, Format::Csv);
+        assert!(options.check && !options.bench);
+        assert_eq!(options.answers, PathBuf::from("out"));
+        assert_eq!((options.warmup, options.runs), (3, 5));
+        assert_eq!(parse(&[]).expect("Valid options").format, Format::Text);
+    }
+
+    #[test]
+    fn rejects_bad_options() {
+        assert_eq!(parse(&["--verbose"]).err(), Some("Unknown option: --verbose".to_string()));
+        assert_eq!(parse(&["run", "-x"]).err(), Some("Unknown option: -x".to_string()));
+        assert_eq!(parse(&["--format", "xml"]).err(), Some("Unknown output format: xml".to_string()));
+        assert_eq!(parse(&["--format"]).err(), Some("Missing value for --format".to_string()));
+        assert_eq!(parse(&["--warmup", "many"]).err(), Some("Invalid value for --warmup".to_string()));
+        assert_eq!(parse(&["--runs", "0"]).err(), Some("--runs must be at least 1".to_string()));
+    }
+
+    #[test]
+    fn rejects_options_of_other_commands() {
+        let options = parse(&["run", "7", "-f", "json", "--inputs", "in"]).expect("Valid options");
+        assert_eq!(options.given, ["--format", "--inputs"]);
+        assert_eq!(options.only(RUN_OPTIONS, "run"), Ok(()));
+        assert_eq!(options.only(BENCH_OPTIONS, "bench"), Err("--format does not apply to bench".to_string()));
+        let options = parse(&["run", "7", "--check", "--runs", "5"]).expect("Valid options");
+        assert_eq!(options.only(RUN_OPTIONS, "run"), Err("--runs does not apply to run".to_string()));
+        assert_eq!(parse(&["list"]).expect("Valid options").only(&[], "list"), Ok(()));
+    }
+}
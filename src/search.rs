@@ -0,0 +1,259 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::Add;
+use crate::{Coord, Grid};
+
+/// Cheapest route found by a search, `states` running from the start to the goal inclusive.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Cheapest path from any of `starts` to a state satisfying `is_goal`, `None` when unreachable.
+pub fn dijkstra<S, C, I, N, NI, G>(starts: I, successors: N, is_goal: G) -> Option<Path<S, C>>
+    where
+        S: Clone + Hash + Eq,
+        C: Copy + Ord + Add<Output=C> + Default,
+        I: IntoIterator<Item=S>,
+        N: FnMut(&S) -> NI,
+        NI: IntoIterator<Item=(S, C)>,
+        G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate the remaining cost.
+pub fn astar<S, C, I, N, NI, H, G>(starts: I, mut successors: N, mut heuristic: H, mut is_goal: G) -> Option<Path<S, C>>
+    where
+        S: Clone + Hash + Eq,
+        C: Copy + Ord + Add<Output=C> + Default,
+        I: IntoIterator<Item=S>,
+        N: FnMut(&S) -> NI,
+        NI: IntoIterator<Item=(S, C)>,
+        H: FnMut(&S) -> C,
+        G: FnMut(&S) -> bool,
+{
+    // Every state seen so far with its parent and best known cost, the queue refers to them by index
+    let mut nodes: Vec<(S, Option<usize>, C)> = Vec::new();
+    let mut indices = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push((start, None, C::default()));
+        }
+    }
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if cost > nodes[node].2 {
+            continue;
+        }
+        if is_goal(&nodes[node].0) {
+            let mut states = vec![];
+            let mut current = Some(node);
+            while let Some(node) = current {
+                states.push(nodes[node].0.clone());
+                current = nodes[node].1;
+            }
+            states.reverse();
+            return Some(Path { cost, states });
+        }
+        for (next, step_cost) in successors(&nodes[node].0) {
+            let next_cost = cost + step_cost;
+            match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next = entry.key().clone();
+                    queue.push(Reverse((next_cost + heuristic(&next), next_cost, nodes.len())));
+                    entry.insert(nodes.len());
+                    nodes.push((next, Some(node), next_cost));
+                }
+                Entry::Occupied(entry) => {
+                    let next = *entry.get();
+                    if next_cost < nodes[next].2 {
+                        nodes[next].1 = Some(node);
+                        nodes[next].2 = next_cost;
+                        queue.push(Reverse((next_cost + heuristic(&nodes[next].0), next_cost, next)));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// States found by [`bfs`] with their distance from the nearest start and the state they were reached from.
+#[derive(Debug, Clone)]
+pub struct Reached<S> {
+    states: HashMap<S, (usize, Option<S>)>,
+}
+
+impl<S> Reached<S> where S: Clone + Hash + Eq {
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+    pub fn contains(&self, state: &S) -> bool {
+        self.states.contains_key(state)
+    }
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.states.get(state).map(|&(distance, _)| distance)
+    }
+    pub fn parent(&self, state: &S) -> Option<&S> {
+        self.states.get(state)?.1.as_ref()
+    }
+    /// Shortest path from a start to `state` following the parent pointers.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut current = self.states.get(state)?;
+        while let (_, Some(parent)) = current {
+            path.push(parent.clone());
+            current = &self.states[parent];
+        }
+        path.reverse();
+        Some(path)
+    }
+    pub fn reachable(&self) -> impl Iterator<Item=&S> + '_ {
+        self.states.keys()
+    }
+    pub fn distances(&self) -> impl Iterator<Item=(&S, usize)> + '_ {
+        self.states.iter().map(|(state, &(distance, _))| (state, distance))
+    }
+    /// States that can be reached in exactly `steps` steps when moving back and forth is allowed,
+    /// those at most `steps` away with the same parity.
+    pub fn count_with_parity(&self, steps: usize) -> usize {
+        self.states.values()
+            .filter(|&&(distance, _)| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+}
+
+impl Reached<Coord> {
+    pub fn distance_grid(&self, width: usize, height: usize) -> Grid<Option<usize>> {
+        let mut grid = Grid::new(width, height);
+        self.distances()
+            .for_each(|(coord, distance)| {
+                if let Some(cell) = grid.get_mut_coord(coord) {
+                    *cell = Some(distance);
+                }
+            });
+        grid
+    }
+}
+
+/// Breadth-first search from all `starts` at once, stopping `step_limit` steps away when given.
+pub fn bfs<S, I, N, NI>(starts: I, step_limit: Option<usize>, mut successors: N) -> Reached<S>
+    where
+        S: Clone + Hash + Eq,
+        I: IntoIterator<Item=S>,
+        N: FnMut(&S) -> NI,
+        NI: IntoIterator<Item=S>,
+{
+    let mut states = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = states.entry(start.clone()) {
+            entry.insert((0, None));
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((state, distance)) = queue.pop_front() {
+        if step_limit.is_some_and(|step_limit| distance >= step_limit) {
+            continue;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = states.entry(next.clone()) {
+                entry.insert((distance + 1, Some(state.clone())));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    Reached { states }
+}
+
+/// [`bfs`] over the orthogonal neighbours in `grid` whose cells are `passable`.
+pub fn bfs_grid<T, I, P>(grid: &Grid<T>, starts: I, step_limit: Option<usize>, passable: P) -> Reached<Coord>
+    where
+        I: IntoIterator<Item=Coord>,
+        P: Fn(&T) -> bool,
+{
+    let passable = &passable;
+    bfs(starts, step_limit, |&coord| {
+        grid.neighbours(coord)
+            .filter(move |(_, value)| passable(value))
+            .map(|(neighbour, _)| neighbour)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Directed graph where the direct edge 0 -> 3 is more expensive than the detour through 1 and 2
+    fn successors(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10), (4, 1)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            4 => vec![(4, 0), (0, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_path() {
+        let path = dijkstra([0], successors, |&node| node == 3).expect("Reachable");
+        assert_eq!(path, Path { cost: 6, states: vec![0, 1, 2, 3] });
+        assert_eq!(dijkstra([1, 4], successors, |&node| node == 3).map(|path| path.cost), Some(5));
+        assert_eq!(dijkstra([0], successors, |&node| node == 0).map(|path| path.states), Some(vec![0]));
+        assert_eq!(dijkstra([3], successors, |&node| node == 0), None);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_on_grid() {
+        // Open 10x10 plane with a wall at x = 5 that has a gap at y = 9
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && (x != 5 || y == 9))
+                .map(|next| (next, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| 9 - x + y;
+        let path = astar([(0, 0)], successors, heuristic, |&state| state == (9, 0)).expect("Reachable");
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.states.len(), 28);
+        assert!(path.states.contains(&(5, 9)));
+        assert_eq!(dijkstra([(0, 0)], successors, |&state| state == (9, 0)).map(|path| path.cost), Some(27));
+    }
+
+    #[test]
+    fn bfs_distances_and_paths() {
+        let grid = Grid::from_input(["..#.", "..#.", "...."].into_iter(), crate::some).expect("Valid grid");
+        let reached = bfs_grid(&grid, [Coord::new(0, 0)], None, |&c| c != '#');
+        assert_eq!(reached.len(), 10);
+        assert_eq!(reached.distance(&Coord::new(3, 0)), Some(7));
+        assert_eq!(reached.distance(&Coord::new(2, 0)), None);
+        let path = reached.path_to(&Coord::new(3, 0)).expect("Reachable");
+        assert_eq!((path.len(), path[0], path[7]), (8, Coord::new(0, 0), Coord::new(3, 0)));
+        assert!(path.windows(2).all(|step| step[0] - step[1] == 1));
+        let distances = reached.distance_grid(grid.width(), grid.height());
+        assert_eq!(distances.row(2), [Some(2), Some(3), Some(4), Some(5)]);
+        assert_eq!(distances[Coord::new(2, 1)], None);
+    }
+
+    #[test]
+    fn bfs_limits_and_parity() {
+        let successors = |&n: &i32| [n - 1, n + 1];
+        let reached = bfs([0, 10], Some(3), successors);
+        assert_eq!(reached.len(), 14);
+        assert_eq!(reached.distance(&5), None);
+        assert_eq!(reached.distance(&7), Some(3));
+        assert_eq!(reached.parent(&8), Some(&9));
+        assert_eq!(reached.count_with_parity(2), 6);
+        assert_eq!(reached.count_with_parity(3), 8);
+        assert!(reached.reachable().all(|&n| (-3..=13).contains(&n)));
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+use std::panic::catch_unwind;
+use std::time::{Duration, Instant};
+use crate::Error;
+use crate::report::{PartReport, Report};
+
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+fn timed<T, F>(f: F) -> (T, Duration) where F: FnOnce() -> T {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn solve<S>(input: &str) -> Result<Report, Error> where S: Solution {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let (r1, time1) = timed(|| S::part1(&parsed));
+    let (r2, time2) = timed(|| S::part2(&parsed));
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        parts: [
+            PartReport { answer: r1.to_string(), time: time1 },
+            PartReport { answer: r2.to_string(), time: time2 },
+        ],
+    })
+}
+
+/// Type-erased registry entry of a [`Solution`], so that all days can be stored in one list.
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub solve: fn(&str) -> Result<Report, Error>,
+}
+
+impl Day {
+    pub const fn new<S>() -> Self where S: Solution {
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Solves like [`Day::solve`], but turns a panic while solving into an [`Error`],
+    /// so that one failing day does not stop the others.
+    pub fn solve_caught(&self, input: &str) -> Result<Report, Error> {
+        catch_unwind(|| (self.solve)(input))
+            .unwrap_or_else(|payload| {
+                let message = payload.downcast_ref::<&str>().copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("unknown cause");
+                Err(Error::new(format!("Panicked: {message}")))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Panicking";
+        type Input = usize;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input.len())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Output1 {
+            *input
+        }
+
+        fn part2(input: &Self::Input) -> Self::Output2 {
+            assert!(*input < 3, "Input too long: {input}");
+            *input
+        }
+    }
+
+    #[test]
+    fn panics_become_errors() {
+        let day = Day::new::<Panicking>();
+        assert_eq!(day.solve_caught("ab").map(|report| report.parts[1].answer.clone()), Ok("2".to_string()));
+        assert_eq!(day.solve_caught("abc").err().map(|err| err.reason), Some("Panicked: Input too long: 3".to_string()));
+    }
+}